@@ -5,6 +5,8 @@
 use thiserror::Error;
 
 use crate::http_api_v3::data::error::Error as ResponseError;
+use crate::http_api_v3::data::exchange_info::symbol::filter::FilterError;
+use crate::http_api_v3::data::exchange_info::symbol::status::Status as SymbolStatus;
 
 ///
 /// The Binance error.
@@ -17,22 +19,90 @@ pub enum Error {
     /// The request building error. Can happen on invalid user input.
     #[error("request building: {0}")]
     RequestBuilding(reqwest::Error),
+    /// The Ed25519 private key reading or parsing error. Can happen on invalid user input.
+    #[error("Ed25519 private key: {0}")]
+    Ed25519Key(String),
     /// The authorization keys data missing. The client was created without them.
     #[error("authorization keys missing. Please, add create a client with keys")]
     AuthorizationKeysMissing,
+    /// Only one of the API key and the signing key is set, which is always a misconfiguration.
+    #[error("incomplete authorization: both the API key and the signing key must be set")]
+    IncompleteAuthorization,
+    /// The environment variable with the authorization key is not set or is not valid Unicode.
+    #[error("environment variable {0} missing")]
+    MissingEnvVar(String),
+    /// The authorization keys are present, but Binance rejected them with the `-2015` error.
+    /// The API key is invalid or expired, or the IP address or the key permissions are restricted.
+    #[error("invalid API key, IP, or permissions: {0:?}")]
+    InvalidApiKey(ResponseError),
+    /// The request query is invalid, e.g. has an unsupported parameter value. Can happen on
+    /// invalid user input.
+    #[error("invalid request: {0}")]
+    InvalidRequest(String),
+    /// The signed request query is malformed. Can happen on invalid user input.
+    #[error("invalid signed request: {0}")]
+    InvalidSignedRequest(String),
+    /// The order violates the symbol filters. Only checked if enabled in the builder.
+    #[error("order filter: {0}")]
+    OrderFilter(#[from] FilterError),
+    /// The order symbol is not trading, e.g. halted during maintenance. Only checked if enabled
+    /// in the builder.
+    #[error("symbol {0} is not trading: {1:?}")]
+    SymbolNotTrading(String, SymbolStatus),
+    /// The request would exceed the client-side rate limit.
+    #[error("local rate limit: {0}")]
+    LocalRateLimit(String),
     /// The request execution error. Usually happens due to some network errors.
     #[error("request execution: {0}")]
     RequestExecution(reqwest::Error),
     /// The response reading error.
     #[error("response reading: {0}")]
     ResponseReading(reqwest::Error),
+    /// The pinned connection error of the resolving transport.
+    #[error("connection: {0}")]
+    Connection(std::io::Error),
+    /// The response body exceeds the maximal size set in the builder, which is contained.
+    #[error("response larger than {0} bytes")]
+    ResponseTooLarge(usize),
+    /// The response is not JSON, e.g. an HTML error page or an empty body during incidents.
+    /// Contains the HTTP status and the beginning of the body.
+    #[error("non-JSON response with status {0}: {1}")]
+    NonJsonResponse(reqwest::StatusCode, String),
+    /// The HTTP 5xx response, e.g. if Binance is overloaded. The request outcome is unknown, so
+    /// a placed order might or might not have been accepted.
+    /// Contains the HTTP status and the beginning of the body.
+    #[error("server error with status {0}: {1}")]
+    ServerError(reqwest::StatusCode, String),
     /// The response parsing error. Binance returned invalid data or the data model must be updated.
     #[error("response parsing: {0} ({1})")]
     ResponseParsing(serde_json::Error, String),
+    /// The placed order client ID differs from the sent one. Only checked if enabled in the builder.
+    #[error("client order ID mismatch: sent {0}, received {1}")]
+    ClientOrderIdMismatch(String, String),
+    /// Some orders on the symbol are still open after the cancellation timeout.
+    /// Contains the symbol and the number of the open orders.
+    #[error("{1} orders on {0} are still open after the cancellation timeout")]
+    CancelConfirmationTimeout(String, usize),
+    /// The order is still active after the cancellation timeout.
+    /// Contains the symbol and the order ID.
+    #[error("order {1} on {0} is still active after the cancellation timeout")]
+    OrderCancelConfirmationTimeout(String, i64),
+    /// The signed requests have been rejected with the `-1021` or `-1022` errors several times in
+    /// a row, which usually means the system clock is badly wrong and the time must be fixed.
+    /// Contains the number of the consecutive failures and the last error.
+    #[error("clock drift suspected after {0} consecutive timestamp or signature errors, please resynchronize the system clock: {1:?}")]
+    ClockDriftSuspected(usize, ResponseError),
     /// The response is valid, but Binance returned an application-level error.
     #[error("response error: {0:?}")]
-    ResponseError(ResponseError),
+    ResponseError(#[from] ResponseError),
+    /// The order book depth diff does not follow the last applied one, so some diffs are missing.
+    /// Contains the expected and the received first update IDs.
+    #[error("order book gap: expected update {0}, received {1}")]
+    OrderBookGap(i64, i64),
     /// The WebSocket error.
     #[error("WebSocket: {0}")]
     WebSocket(websocket::WebSocketError),
+    /// The WebSocket connection has been closed, so it cannot be controlled anymore.
+    #[error("WebSocket connection closed")]
+    WebSocketClosed,
 }
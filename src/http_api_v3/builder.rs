@@ -0,0 +1,455 @@
+//!
+//! The Binance API v3 HTTP client builder.
+//!
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::atomic::AtomicI64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::Arc;
+use std::sync::RwLock;
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+use reqwest::header::HeaderValue;
+use reqwest::header::USER_AGENT;
+
+use crate::error::Error;
+use crate::http_api_v3::authorization::Authorization;
+use crate::http_api_v3::rate_limiter::Mode as RateLimiterMode;
+use crate::http_api_v3::rate_limiter::RateLimiter;
+use crate::http_api_v3::retry_policy::RetryPolicy;
+use crate::http_api_v3::transport::ReqwestTransport;
+use crate::http_api_v3::transport::ResolvingTransport;
+use crate::http_api_v3::transport::Transport;
+use crate::http_api_v3::Client;
+
+///
+/// The Binance API v3 HTTP client builder.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Builder {
+    /// The Binance authorization API key.
+    api_key: Option<String>,
+    /// The Binance request signing method.
+    authorization: Option<Authorization>,
+    /// The client-side rate limiter mode, if enabled.
+    rate_limiter: Option<RateLimiterMode>,
+    /// The interchangeable API base URLs.
+    base_urls: Option<Vec<String>>,
+    /// The request timestamp padding in milliseconds.
+    timestamp_padding: Option<i64>,
+    /// Whether to check the placed order client IDs.
+    verify_client_order_id: bool,
+    /// Whether to check the order notional against the symbol filters before placing.
+    check_notional: bool,
+    /// Whether to check that the order symbol is trading before placing.
+    check_symbol_status: bool,
+    /// The symbol, which is set to the queries with an empty symbol.
+    default_symbol: Option<String>,
+    /// The default `recvWindow` of the signed requests in milliseconds.
+    recv_window: Option<i64>,
+    /// The local address the requests are sent from.
+    local_address: Option<IpAddr>,
+    /// Whether to request and decompress the gzip-encoded responses.
+    gzip: Option<bool>,
+    /// Whether to accept any TLS certificate. Never enable in production.
+    #[cfg(feature = "danger-accept-invalid-certs")]
+    danger_accept_invalid_certs: bool,
+    /// The `User-Agent` header value.
+    user_agent: Option<String>,
+    /// The maximal response body size in bytes.
+    max_response_size: Option<usize>,
+    /// Whether to resynchronize the time and retry once on the `-1021` error.
+    resync_on_timestamp_error: bool,
+    /// The number of the consecutive `-1021` or `-1022` errors, after which the clock drift is
+    /// reported.
+    clock_drift_threshold: Option<usize>,
+    /// The pinned socket addresses by host.
+    addresses: HashMap<String, SocketAddr>,
+    /// The custom HTTP transport.
+    transport: Option<Arc<dyn Transport>>,
+    /// The retry policy of the failed requests.
+    retry_policy: RetryPolicy,
+    /// The time the cached exchange info is considered fresh.
+    exchange_info_ttl: Option<Duration>,
+}
+
+impl Builder {
+    ///
+    /// Creates a builder of an unauthorized client.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Sets the Binance authorization API key.
+    ///
+    pub fn api_key(mut self, api_key: String) -> Self {
+        self.api_key = Some(api_key);
+        self
+    }
+
+    ///
+    /// Sets the HMAC secret key, which is used to sign authorized requests.
+    ///
+    pub fn secret_key(mut self, secret_key: String) -> Self {
+        self.authorization = Some(Authorization::Hmac(secret_key));
+        self
+    }
+
+    ///
+    /// Sets the Ed25519 private key in the PKCS#8 PEM format, which is used to sign
+    /// authorized requests.
+    ///
+    pub fn ed25519_key(mut self, pem: &str) -> Result<Self, Error> {
+        self.authorization = Some(Authorization::ed25519_from_pem(pem)?);
+        Ok(self)
+    }
+
+    ///
+    /// Reads the Ed25519 private key from the PKCS#8 PEM file, which is used to sign
+    /// authorized requests.
+    ///
+    pub fn ed25519_key_file<P: AsRef<Path>>(mut self, path: P) -> Result<Self, Error> {
+        self.authorization = Some(Authorization::ed25519_from_file(path)?);
+        Ok(self)
+    }
+
+    ///
+    /// Enables the client-side rate limiter, which reads the limits from the exchange info
+    /// and either blocks or fails the requests that would exceed them.
+    ///
+    pub fn rate_limiter(mut self, mode: RateLimiterMode) -> Self {
+        self.rate_limiter = Some(mode);
+        self
+    }
+
+    ///
+    /// Sets the interchangeable API base URLs, e.g. `https://api1.binance.com`, which are tried
+    /// in turn on connection failures.
+    ///
+    /// Defaults to `Client::BASE_URLS`, which are also used if `base_urls` is empty.
+    ///
+    pub fn base_urls(mut self, base_urls: Vec<String>) -> Self {
+        self.base_urls = Some(base_urls);
+        self
+    }
+
+    ///
+    /// Sets the request timestamp padding in milliseconds, which backdates the signed requests.
+    ///
+    /// Defaults to `Client::REQUEST_TIMESTAMP_OFFSET`, which protects from the requests being
+    /// rejected as sent from the future, if the system clock drifts ahead after the time
+    /// synchronization. However, the padding is consumed from `recvWindow`, so it may be
+    /// reduced or zeroed with a tight `recvWindow` and a well-synchronized clock.
+    ///
+    pub fn timestamp_padding(mut self, timestamp_padding: i64) -> Self {
+        self.timestamp_padding = Some(timestamp_padding);
+        self
+    }
+
+    ///
+    /// Enables the check that the client order ID returned on placing an order is the same as
+    /// the `new_client_order_id` sent, which fails with `ClientOrderIdMismatch` otherwise.
+    ///
+    pub fn verify_client_order_id(mut self, verify_client_order_id: bool) -> Self {
+        self.verify_client_order_id = verify_client_order_id;
+        self
+    }
+
+    ///
+    /// Enables the check that the placed limit orders satisfy the symbol notional filters, which
+    /// fails with `OrderFilter` instead of sending the order.
+    ///
+    /// The symbol filters are read from the cached exchange info, which is refreshed after
+    /// `exchange_info_ttl`.
+    ///
+    pub fn check_notional(mut self, check_notional: bool) -> Self {
+        self.check_notional = check_notional;
+        self
+    }
+
+    ///
+    /// Enables the check that the order symbol status is `TRADING`, which fails with
+    /// `SymbolNotTrading` instead of sending the order, e.g. during maintenance.
+    ///
+    /// The symbol status is read from the cached exchange info, which is refreshed after
+    /// `exchange_info_ttl`.
+    ///
+    pub fn check_symbol_status(mut self, check_symbol_status: bool) -> Self {
+        self.check_symbol_status = check_symbol_status;
+        self
+    }
+
+    ///
+    /// Sets the default symbol, e.g. `BTCUSDT` for a single-pair bot, which is set to the queries
+    /// with an empty `symbol`, e.g. `OrderGetQuery::new("", client_order_id)`.
+    ///
+    /// A non-empty symbol set on a query always overrides the default. The queries, where the
+    /// symbol is optional and its absence means all the symbols, are not affected.
+    ///
+    pub fn default_symbol(mut self, default_symbol: String) -> Self {
+        self.default_symbol = Some(default_symbol);
+        self
+    }
+
+    ///
+    /// Sets the default `recvWindow` in milliseconds, which is added to the signed requests.
+    ///
+    /// The `recv_window` set on a query overrides the default for that single call, e.g. a wider
+    /// window for a slow history request and a tight one for the order placement.
+    ///
+    /// Binance caps the window at `Client::RECV_WINDOW_MAX`, that is, 60000 ms, so both the
+    /// default and the per-query windows above it fail locally with `InvalidRequest`, when the
+    /// client is built and when a query is signed respectively.
+    ///
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    ///
+    /// Sets the local address the requests are sent from, e.g. the IP address whitelisted for
+    /// the API key on a multi-homed host.
+    ///
+    pub fn local_address(mut self, local_address: IpAddr) -> Self {
+        self.local_address = Some(local_address);
+        self
+    }
+
+    ///
+    /// Enables the gzip response compression, which reduces the size of the heavy responses,
+    /// e.g. the exchange info and the full depth.
+    ///
+    /// Enabled by default.
+    ///
+    pub fn gzip(mut self, gzip: bool) -> Self {
+        self.gzip = Some(gzip);
+        self
+    }
+
+    ///
+    /// DANGER: disables the TLS certificate verification, so the API keys and the signed
+    /// requests can be intercepted by anyone on the network path.
+    ///
+    /// Only meant for debugging the traffic via a local proxy with a self-signed certificate,
+    /// e.g. mitmproxy or Charles, and never for production. Requires the
+    /// `danger-accept-invalid-certs` feature.
+    ///
+    #[cfg(feature = "danger-accept-invalid-certs")]
+    pub fn danger_accept_invalid_certs(mut self, danger_accept_invalid_certs: bool) -> Self {
+        self.danger_accept_invalid_certs = danger_accept_invalid_certs;
+        self
+    }
+
+    ///
+    /// Sets the `User-Agent` header value, e.g. to tell several bots on one account apart in the
+    /// server-side logs.
+    ///
+    /// Defaults to `Client::USER_AGENT`.
+    ///
+    pub fn user_agent(mut self, user_agent: String) -> Self {
+        self.user_agent = Some(user_agent);
+        self
+    }
+
+    ///
+    /// Sets the maximal response body size in bytes, beyond which the response is rejected with
+    /// `ResponseTooLarge`, e.g. to protect a long-running service from a malformed response.
+    ///
+    /// Defaults to `ReqwestTransport::MAX_RESPONSE_SIZE_DEFAULT`, that is, 32 MB.
+    ///
+    pub fn max_response_size(mut self, max_response_size: usize) -> Self {
+        self.max_response_size = Some(max_response_size);
+        self
+    }
+
+    ///
+    /// Enables the time resynchronization on the `-1021` error, that is, the request timestamp
+    /// is outside of `recvWindow`, after which the signed request is re-signed with the new
+    /// timestamp and retried exactly once.
+    ///
+    /// The retry is safe for placing orders, since Binance rejects such requests before
+    /// executing them.
+    ///
+    pub fn resync_on_timestamp_error(mut self, resync_on_timestamp_error: bool) -> Self {
+        self.resync_on_timestamp_error = resync_on_timestamp_error;
+        self
+    }
+
+    ///
+    /// Sets the number of the consecutive `-1021` or `-1022` errors of the signed requests,
+    /// after which they fail with `ClockDriftSuspected` until a signed request succeeds.
+    ///
+    /// Defaults to `Client::CLOCK_DRIFT_THRESHOLD_DEFAULT`, that is, 3.
+    ///
+    pub fn clock_drift_threshold(mut self, clock_drift_threshold: usize) -> Self {
+        self.clock_drift_threshold = Some(clock_drift_threshold);
+        self
+    }
+
+    ///
+    /// Pins the `domain` to the socket `address`, e.g. the closest Binance edge server, so it is
+    /// connected without the DNS resolution. The TLS server name and the `Host` header are
+    /// still set to the domain.
+    ///
+    /// The `local_address` and `gzip` options do not apply to the pinned connections.
+    ///
+    pub fn resolve(mut self, domain: &str, address: SocketAddr) -> Self {
+        self.addresses.insert(domain.to_owned(), address);
+        self
+    }
+
+    ///
+    /// Sets the custom HTTP transport, e.g. a fake one returning the canned responses in tests.
+    ///
+    /// The `local_address`, `gzip`, `user_agent`, `max_response_size`, `resolve`, and
+    /// `danger_accept_invalid_certs` options only apply to the default `reqwest` transport.
+    ///
+    pub fn transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    ///
+    /// Sets the retry policy of the failed idempotent requests.
+    ///
+    /// Defaults to `RetryPolicy::none()`.
+    ///
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    ///
+    /// Sets the time the exchange info returned by `exchange_info_cached` is considered fresh.
+    ///
+    /// Defaults to `Client::EXCHANGE_INFO_TTL_DEFAULT`, that is, one hour.
+    ///
+    pub fn exchange_info_ttl(mut self, exchange_info_ttl: Duration) -> Self {
+        self.exchange_info_ttl = Some(exchange_info_ttl);
+        self
+    }
+
+    ///
+    /// Creates the client and synchronizes its time with Binance.
+    ///
+    /// If the rate limiter or the notional check is enabled, the exchange info is requested to
+    /// get the limits and to fill the exchange info cache.
+    ///
+    /// Fails with `IncompleteAuthorization` if only one of the API key and the signing key is set.
+    ///
+    pub fn build(self) -> Result<Client, Error> {
+        if self.api_key.is_some() != self.authorization.is_some() {
+            return Err(Error::IncompleteAuthorization);
+        }
+        if let Some(recv_window) = self.recv_window {
+            Client::validate_recv_window(recv_window)?;
+        }
+
+        let transport = match self.transport {
+            Some(transport) => transport,
+            None => {
+                let mut inner = reqwest::Client::builder();
+                if let Some(local_address) = self.local_address {
+                    inner = inner.local_address(local_address);
+                }
+                inner = inner.gzip(self.gzip.unwrap_or(true));
+                #[cfg(feature = "danger-accept-invalid-certs")]
+                {
+                    if self.danger_accept_invalid_certs {
+                        log::warn!("TLS certificate verification is disabled");
+                    }
+                    inner = inner.danger_accept_invalid_certs(self.danger_accept_invalid_certs);
+                }
+
+                let user_agent = self
+                    .user_agent
+                    .unwrap_or_else(|| Client::USER_AGENT.to_owned());
+                let mut headers = HeaderMap::new();
+                headers.insert(
+                    USER_AGENT,
+                    HeaderValue::from_str(user_agent.as_str()).map_err(|error| {
+                        Error::InvalidRequest(format!("User-Agent {}: {}", user_agent, error))
+                    })?,
+                );
+                inner = inner.default_headers(headers);
+
+                let max_response_size = self
+                    .max_response_size
+                    .unwrap_or(ReqwestTransport::MAX_RESPONSE_SIZE_DEFAULT);
+                let inner = ReqwestTransport::new(inner.build().map_err(Error::RequestBuilding)?)
+                    .max_response_size(max_response_size);
+                if self.addresses.is_empty() {
+                    Arc::new(inner) as Arc<dyn Transport>
+                } else {
+                    #[cfg_attr(not(feature = "danger-accept-invalid-certs"), allow(unused_mut))]
+                    let mut tls = native_tls::TlsConnector::builder();
+                    #[cfg(feature = "danger-accept-invalid-certs")]
+                    tls.danger_accept_invalid_certs(self.danger_accept_invalid_certs);
+                    let tls = tls
+                        .build()
+                        .map_err(|error| Error::Connection(std::io::Error::other(error)))?;
+                    Arc::new(
+                        ResolvingTransport::new(inner, self.addresses, tls)
+                            .user_agent(user_agent)
+                            .max_response_size(max_response_size),
+                    )
+                }
+            }
+        };
+
+        let mut client = Client {
+            transport,
+            api_key: self.api_key,
+            authorization: self.authorization,
+            timestamp_offset: Arc::new(AtomicI64::new(0)),
+            rate_limiter: None,
+            base_urls: Arc::new(
+                self.base_urls
+                    .filter(|base_urls| !base_urls.is_empty())
+                    .unwrap_or_else(|| {
+                        Client::BASE_URLS
+                            .iter()
+                            .map(|base_url| (*base_url).to_owned())
+                            .collect()
+                    }),
+            ),
+            base_url_index: Arc::new(AtomicUsize::new(0)),
+            timestamp_padding: self
+                .timestamp_padding
+                .unwrap_or(Client::REQUEST_TIMESTAMP_OFFSET),
+            verify_client_order_id: self.verify_client_order_id,
+            check_symbol_status: self.check_symbol_status,
+            default_symbol: self.default_symbol,
+            check_notional: self.check_notional,
+            recv_window: self.recv_window,
+            resync_on_timestamp_error: self.resync_on_timestamp_error,
+            clock_drift_threshold: self
+                .clock_drift_threshold
+                .unwrap_or(Client::CLOCK_DRIFT_THRESHOLD_DEFAULT),
+            timestamp_failures: Arc::new(AtomicUsize::new(0)),
+            retry_policy: self.retry_policy,
+            exchange_info_ttl: self
+                .exchange_info_ttl
+                .unwrap_or(Client::EXCHANGE_INFO_TTL_DEFAULT),
+            exchange_info_cache: Arc::new(RwLock::new(None)),
+        };
+
+        client.synchronize_time()?;
+        if self.rate_limiter.is_some() || self.check_notional {
+            let exchange_info = client.exchange_info_cached()?;
+            if let Some(mode) = self.rate_limiter {
+                client.rate_limiter = Some(Arc::new(RateLimiter::new(
+                    exchange_info.rate_limits.as_slice(),
+                    mode,
+                )));
+            }
+        }
+        Ok(client)
+    }
+}
@@ -2,15 +2,18 @@
 //! The account GET request.
 //!
 
-use chrono::prelude::*;
+use crate::http_api_v3::signed_query::SignedQuery;
 
 ///
 /// The `https://www.binance.com/api/v3/order` GET request query.
 ///
+#[derive(Debug)]
 pub struct Query {
+    /// Whether to omit the zero balances from the response.
+    pub omit_zero_balances: Option<bool>,
     /// The allowed time window between the request and response in milliseconds.
     pub recv_window: Option<i64>,
-    /// The request time in milliseconds.
+    /// The request time in milliseconds, which is set by the client on signing.
     pub timestamp: i64,
 }
 
@@ -29,19 +32,37 @@ impl Query {
     ///
     pub fn new() -> Self {
         Self {
+            omit_zero_balances: None,
             recv_window: None,
-            timestamp: Utc::now().timestamp_millis(),
+            timestamp: 0,
         }
     }
+
+    ///
+    /// The request weight.
+    ///
+    pub fn weight(&self) -> u32 {
+        20
+    }
 }
 
 impl ToString for Query {
     fn to_string(&self) -> String {
-        let mut params = String::with_capacity(Self::QUERY_INITIAL_CAPACITY);
+        let mut params =
+            form_urlencoded::Serializer::new(String::with_capacity(Self::QUERY_INITIAL_CAPACITY));
+        if let Some(omit_zero_balances) = self.omit_zero_balances {
+            params.append_pair("omitZeroBalances", &omit_zero_balances.to_string());
+        }
         if let Some(recv_window) = self.recv_window {
-            params += &format!("&recvWindow={}", recv_window.to_string());
+            params.append_pair("recvWindow", &recv_window.to_string());
         }
-        params += &format!("&timestamp={}", self.timestamp.to_string());
-        params
+        params.append_pair("timestamp", &self.timestamp.to_string());
+        params.finish()
+    }
+}
+
+impl SignedQuery for Query {
+    fn set_timestamp(&mut self, timestamp: i64) {
+        self.timestamp = timestamp;
     }
 }
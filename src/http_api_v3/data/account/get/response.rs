@@ -2,6 +2,9 @@
 //! The account GET response.
 //!
 
+use std::collections::HashMap;
+
+use chrono::prelude::*;
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
@@ -15,14 +18,14 @@ use crate::http_api_v3::data::permission::Permission;
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Response {
-    /// The maker fee.
-    pub maker_commission: i64,
-    /// The taker fee.
-    pub taker_commission: i64,
-    /// The buyer fee.
-    pub buyer_commission: i64,
-    /// The seller fee.
-    pub seller_commission: i64,
+    /// The maker fee in basis points.
+    pub maker_commission: u32,
+    /// The taker fee in basis points.
+    pub taker_commission: u32,
+    /// The buyer fee in basis points.
+    pub buyer_commission: u32,
+    /// The seller fee in basis points.
+    pub seller_commission: u32,
     /// Whether the account is allowed to trade.
     pub can_trade: bool,
     /// Whether the account is allowed to withdraw.
@@ -36,18 +39,99 @@ pub struct Response {
     /// The account balances.
     pub balances: Vec<Balance>,
     /// The account permissions.
+    #[serde(default)]
     pub permissions: Vec<Permission>,
 }
 
 impl Response {
+    ///
+    /// Whether the account is allowed to spot trade, i.e. neither locked nor missing the
+    /// `SPOT` permission.
+    ///
+    pub fn can_trade(&self) -> bool {
+        self.can_trade && self.has_permission(Permission::Spot)
+    }
+
+    ///
+    /// Whether the account has the `permission`.
+    ///
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.permissions.contains(&permission)
+    }
+
+    ///
+    /// The maker fee rate, e.g. `0.001` for 10 basis points.
+    ///
+    pub fn maker_rate(&self) -> Decimal {
+        Self::commission_rate(self.maker_commission)
+    }
+
+    ///
+    /// The taker fee rate, e.g. `0.001` for 10 basis points.
+    ///
+    pub fn taker_rate(&self) -> Decimal {
+        Self::commission_rate(self.taker_commission)
+    }
+
+    ///
+    /// The account last update time as a date and time.
+    ///
+    pub fn update_datetime(&self) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(self.update_time)
+            .single()
+            .expect("Binance timestamps are within the date and time range")
+    }
+
     ///
     /// Get the available balance for the specified token.
     ///
     pub fn get_balance(&self, token: &str) -> Decimal {
+        self.free_balance(token)
+    }
+
+    ///
+    /// Get the balance of the specified token, if present.
+    ///
+    pub fn balance(&self, asset: &str) -> Option<&Balance> {
         self.balances
             .iter()
-            .find(|balance| balance.asset.as_str() == token)
+            .find(|balance| balance.asset.as_str() == asset)
+    }
+
+    ///
+    /// Builds the map of the balances by token, e.g. for the repeated lookups in the portfolio
+    /// valuation.
+    ///
+    pub fn balance_map(&self) -> HashMap<String, Balance> {
+        self.balances
+            .iter()
+            .map(|balance| (balance.asset.clone(), balance.clone()))
+            .collect()
+    }
+
+    ///
+    /// Get the free balance of the specified token, which is zero if absent.
+    ///
+    pub fn free_balance(&self, asset: &str) -> Decimal {
+        self.balance(asset)
             .map(|balance| balance.free)
             .unwrap_or_default()
     }
+
+    ///
+    /// Iterates over the balances with either free or locked amount.
+    ///
+    pub fn non_zero_balances(&self) -> impl Iterator<Item = &Balance> {
+        let zero = Decimal::new(0, 0);
+        self.balances
+            .iter()
+            .filter(move |balance| balance.free != zero || balance.locked != zero)
+    }
+
+    ///
+    /// Converts the fee in basis points into a rate.
+    ///
+    fn commission_rate(commission: u32) -> Decimal {
+        Decimal::new(i64::from(commission), 4)
+    }
 }
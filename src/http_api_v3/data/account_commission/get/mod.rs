@@ -0,0 +1,6 @@
+//!
+//! The account commission GET.
+//!
+
+pub mod request;
+pub mod response;
@@ -0,0 +1,81 @@
+//!
+//! The account commission GET response.
+//!
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+///
+/// The `https://www.binance.com/api/v3/account/commission` GET response.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    /// The symbol name.
+    pub symbol: String,
+    /// The standard commission rates of the account on the symbol.
+    pub standard_commission: Commission,
+    /// The tax commission rates of the account on the symbol.
+    pub tax_commission: Commission,
+    /// The commission discount, e.g. for paying the commission in BNB.
+    pub discount: Discount,
+}
+
+///
+/// The commission rates, e.g. `0.001` for 0.1%.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Commission {
+    /// The maker order rate.
+    pub maker: Decimal,
+    /// The taker order rate.
+    pub taker: Decimal,
+    /// The buy order rate.
+    pub buyer: Decimal,
+    /// The sell order rate.
+    pub seller: Decimal,
+}
+
+///
+/// The commission discount.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Discount {
+    /// If the discount is enabled for the account.
+    pub enabled_for_account: bool,
+    /// If the discount is enabled for the symbol.
+    pub enabled_for_symbol: bool,
+    /// The asset, which the commission must be paid in to get the discount.
+    pub discount_asset: String,
+    /// The multiplier of the standard commission rates, e.g. `0.75` for the 25% discount.
+    pub discount: Decimal,
+}
+
+impl Response {
+    ///
+    /// The maker commission rate, including the discount if it is enabled.
+    ///
+    pub fn effective_maker(&self) -> Decimal {
+        self.standard_commission.maker * self.discount_multiplier()
+    }
+
+    ///
+    /// The taker commission rate, including the discount if it is enabled.
+    ///
+    pub fn effective_taker(&self) -> Decimal {
+        self.standard_commission.taker * self.discount_multiplier()
+    }
+
+    ///
+    /// The discount multiplier if the discount is enabled, and `1` otherwise.
+    ///
+    fn discount_multiplier(&self) -> Decimal {
+        if self.discount.enabled_for_account && self.discount.enabled_for_symbol {
+            self.discount.discount
+        } else {
+            Decimal::new(1, 0)
+        }
+    }
+}
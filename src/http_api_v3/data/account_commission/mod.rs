@@ -0,0 +1,5 @@
+//!
+//! The account commission.
+//!
+
+pub mod get;
@@ -0,0 +1,6 @@
+//!
+//! The aggregate trades GET.
+//!
+
+pub mod request;
+pub mod response;
@@ -0,0 +1,128 @@
+//!
+//! The aggregate trades GET request.
+//!
+
+use std::fmt;
+
+use crate::error::Error;
+use crate::http_api_v3::data::time_range;
+
+///
+/// The `https://www.binance.com/api/v3/aggTrades` GET request query.
+///
+/// If neither `from_id` nor the time range is set, the latest trades are returned.
+///
+#[derive(Debug)]
+pub struct Query {
+    /// The symbol name.
+    pub symbol: String,
+    /// The aggregate trade ID to start from, inclusive.
+    pub from_id: Option<i64>,
+    /// The left time boundary of the requested trades in milliseconds, inclusive.
+    pub start_time: Option<i64>,
+    /// The right time boundary of the requested trades in milliseconds, inclusive.
+    pub end_time: Option<i64>,
+    /// The maximal number of trades. Default is 500, maximum is 1000.
+    pub limit: Option<usize>,
+}
+
+impl Query {
+    /// The query params default capacity.
+    const QUERY_INITIAL_CAPACITY: usize = 128;
+    /// The maximal number of trades per request.
+    pub const LIMIT_MAX: usize = 1000;
+    /// The maximal time range in milliseconds, which is 1 hour.
+    pub const TIME_RANGE_MAX: i64 = time_range::DAY_MS / 24;
+
+    ///
+    /// A shortcut constructor, which finds the latest trades on the symbol.
+    ///
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            from_id: None,
+            start_time: None,
+            end_time: None,
+            limit: None,
+        }
+    }
+
+    ///
+    /// A shortcut constructor, which finds the trades within the time range.
+    ///
+    pub fn new_by_time(symbol: &str, start_time: i64, end_time: i64) -> Self {
+        Self {
+            start_time: Some(start_time),
+            end_time: Some(end_time),
+            ..Self::new(symbol)
+        }
+    }
+
+    ///
+    /// Checks that the time range is not inverted and is at most `TIME_RANGE_MAX`, and that the
+    /// limit is at most `LIMIT_MAX`, since Binance rejects the larger values.
+    ///
+    pub fn validate(&self) -> Result<(), Error> {
+        time_range::validate(self.start_time, self.end_time, Some(Self::TIME_RANGE_MAX))?;
+        match self.limit {
+            Some(limit) if limit > Self::LIMIT_MAX => Err(Error::InvalidRequest(format!(
+                "aggregate trades limit {} exceeds the maximum of {}",
+                limit,
+                Self::LIMIT_MAX
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    ///
+    /// The request weight.
+    ///
+    pub fn weight(&self) -> u32 {
+        4
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut params =
+            form_urlencoded::Serializer::new(String::with_capacity(Self::QUERY_INITIAL_CAPACITY));
+        params.append_pair("symbol", self.symbol.as_str());
+        if let Some(from_id) = self.from_id {
+            params.append_pair("fromId", &from_id.to_string());
+        }
+        if let Some(start_time) = self.start_time {
+            params.append_pair("startTime", &start_time.to_string());
+        }
+        if let Some(end_time) = self.end_time {
+            params.append_pair("endTime", &end_time.to_string());
+        }
+        if let Some(limit) = self.limit {
+            params.append_pair("limit", &limit.to_string());
+        }
+        write!(f, "{}", params.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+
+    use super::Query;
+
+    #[test]
+    fn limit_above_maximum_is_rejected() {
+        let mut query = Query::new("BTCUSDT");
+        query.limit = Some(Query::LIMIT_MAX + 1);
+        match query.validate() {
+            Err(Error::InvalidRequest(_)) => {}
+            result => panic!("expected InvalidRequest, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn maximal_limit_passes() {
+        let mut query = Query::new("BTCUSDT");
+        query.limit = Some(Query::LIMIT_MAX);
+        assert!(query.validate().is_ok());
+    }
+}
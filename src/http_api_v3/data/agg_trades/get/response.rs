@@ -0,0 +1,63 @@
+//!
+//! The aggregate trades GET response.
+//!
+
+use chrono::prelude::*;
+use serde::Deserialize;
+
+use crate::http_api_v3::data::serde::deserialize_price;
+use crate::http_api_v3::data::serde::PriceType;
+
+///
+/// The `https://www.binance.com/api/v3/aggTrades` GET response.
+///
+pub type Response = Vec<AggTrade>;
+
+///
+/// The aggregate trade, that is, the trades of a single taker order at a single price.
+///
+#[derive(Debug, Deserialize, Clone)]
+pub struct AggTrade {
+    /// The aggregate trade ID.
+    #[serde(rename = "a")]
+    pub agg_trade_id: i64,
+    /// The trade price.
+    #[serde(rename = "p", deserialize_with = "deserialize_price")]
+    pub price: PriceType,
+    /// The trade quantity in the secondary asset.
+    #[serde(rename = "q", deserialize_with = "deserialize_price")]
+    pub quantity: PriceType,
+    /// The first aggregated trade ID.
+    #[serde(rename = "f")]
+    pub first_trade_id: i64,
+    /// The last aggregated trade ID.
+    #[serde(rename = "l")]
+    pub last_trade_id: i64,
+    /// The trade time in milliseconds since Unix epoch.
+    #[serde(rename = "T")]
+    pub time: i64,
+    /// If the buyer is the market maker.
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+    /// If the trade is the best price match.
+    #[serde(rename = "M")]
+    pub is_best_match: bool,
+}
+
+impl AggTrade {
+    ///
+    /// The trade volume in the primary asset, that is, `price * quantity`.
+    ///
+    pub fn notional(&self) -> PriceType {
+        self.price * self.quantity
+    }
+
+    ///
+    /// The trade time as a date and time.
+    ///
+    pub fn datetime(&self) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(self.time)
+            .single()
+            .expect("Binance timestamps are within the date and time range")
+    }
+}
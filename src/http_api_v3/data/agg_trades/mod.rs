@@ -0,0 +1,5 @@
+//!
+//! The aggregate trades.
+//!
+
+pub mod get;
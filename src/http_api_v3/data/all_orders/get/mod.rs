@@ -0,0 +1,6 @@
+//!
+//! The all orders GET.
+//!
+
+pub mod request;
+pub mod response;
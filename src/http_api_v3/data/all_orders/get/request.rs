@@ -0,0 +1,97 @@
+//!
+//! The all orders GET request.
+//!
+
+use std::fmt;
+
+use crate::error::Error;
+use crate::http_api_v3::data::time_range;
+use crate::http_api_v3::signed_query::SignedQuery;
+
+///
+/// The `https://www.binance.com/api/v3/allOrders` GET request query.
+///
+#[derive(Debug)]
+pub struct Query {
+    /// The symbol name.
+    pub symbol: String,
+    /// The order ID to start from. The most recent orders are returned if not set.
+    pub order_id: Option<i64>,
+    /// The left time boundary of the requested orders.
+    pub start_time: Option<i64>,
+    /// The right time boundary of the requested orders.
+    pub end_time: Option<i64>,
+    /// The maximum number of orders to get. 500 by default, 1000 at most.
+    pub limit: Option<usize>,
+    /// The allowed time window between the request and response in milliseconds.
+    pub recv_window: Option<i64>,
+    /// The request time in milliseconds, which is set by the client on signing.
+    pub timestamp: i64,
+}
+
+impl Query {
+    /// The query params default capacity.
+    const QUERY_INITIAL_CAPACITY: usize = 256;
+    /// The maximal time range in milliseconds, which is 24 hours.
+    pub const TIME_RANGE_MAX: i64 = time_range::DAY_MS;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            order_id: None,
+            start_time: None,
+            end_time: None,
+            limit: None,
+            recv_window: None,
+            timestamp: 0,
+        }
+    }
+
+    ///
+    /// Checks that the time range is not inverted and is at most `TIME_RANGE_MAX`.
+    ///
+    pub fn validate(&self) -> Result<(), Error> {
+        time_range::validate(self.start_time, self.end_time, Some(Self::TIME_RANGE_MAX))
+    }
+
+    ///
+    /// The request weight.
+    ///
+    pub fn weight(&self) -> u32 {
+        20
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut params =
+            form_urlencoded::Serializer::new(String::with_capacity(Self::QUERY_INITIAL_CAPACITY));
+        params.append_pair("symbol", self.symbol.as_str());
+        if let Some(order_id) = self.order_id {
+            params.append_pair("orderId", &order_id.to_string());
+        }
+        if let Some(start_time) = self.start_time {
+            params.append_pair("startTime", &start_time.to_string());
+        }
+        if let Some(end_time) = self.end_time {
+            params.append_pair("endTime", &end_time.to_string());
+        }
+        if let Some(limit) = self.limit {
+            params.append_pair("limit", &limit.to_string());
+        }
+        if let Some(recv_window) = self.recv_window {
+            params.append_pair("recvWindow", &recv_window.to_string());
+        }
+        params.append_pair("timestamp", &self.timestamp.to_string());
+        write!(f, "{}", params.finish())
+    }
+}
+
+impl SignedQuery for Query {
+    fn set_timestamp(&mut self, timestamp: i64) {
+        self.timestamp = timestamp;
+    }
+}
@@ -0,0 +1,10 @@
+//!
+//! The all orders GET response.
+//!
+
+use crate::http_api_v3::data::order::Order;
+
+///
+/// The `https://www.binance.com/api/v3/allOrders` GET response.
+///
+pub type Response = Vec<Order>;
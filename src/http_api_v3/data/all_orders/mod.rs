@@ -0,0 +1,5 @@
+//!
+//! The all orders.
+//!
+
+pub mod get;
@@ -0,0 +1,6 @@
+//!
+//! The book ticker GET.
+//!
+
+pub mod request;
+pub mod response;
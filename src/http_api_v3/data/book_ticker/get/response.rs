@@ -0,0 +1,56 @@
+//!
+//! The book ticker GET response.
+//!
+
+use serde::Deserialize;
+
+use crate::http_api_v3::data::serde::deserialize_price;
+use crate::http_api_v3::data::serde::PriceType;
+
+///
+/// The `https://www.binance.com/api/v3/ticker/bookTicker` GET response.
+///
+/// A single ticker is returned for the `symbol` query, and a list otherwise.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum Response {
+    /// The ticker of the single requested symbol.
+    Single(BookTicker),
+    /// The tickers of several or all symbols.
+    Multiple(Vec<BookTicker>),
+}
+
+impl Response {
+    ///
+    /// Converts the response into a list of tickers.
+    ///
+    pub fn into_vec(self) -> Vec<BookTicker> {
+        match self {
+            Self::Single(ticker) => vec![ticker],
+            Self::Multiple(tickers) => tickers,
+        }
+    }
+}
+
+///
+/// The best price and quantity on the order book of a symbol.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BookTicker {
+    /// The symbol name.
+    pub symbol: String,
+    /// The best bid price.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub bid_price: PriceType,
+    /// The best bid quantity.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub bid_qty: PriceType,
+    /// The best ask price.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub ask_price: PriceType,
+    /// The best ask quantity.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub ask_qty: PriceType,
+}
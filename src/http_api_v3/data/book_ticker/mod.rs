@@ -0,0 +1,5 @@
+//!
+//! The book ticker.
+//!
+
+pub mod get;
@@ -2,19 +2,27 @@
 //! The depth GET request.
 //!
 
+use crate::error::Error;
+
 ///
 /// The `https://www.binance.com/api/v3/depth` GET request query.
 ///
+#[derive(Debug)]
 pub struct Query {
     /// The symbol name.
     pub symbol: String,
-    /// The maximum number of depth elements to return.
+    /// The maximum number of depth elements to return. Must be one of `Query::LIMITS`.
+    /// Default is 100.
     pub limit: Option<i64>,
 }
 
 impl Query {
     /// The query params default capacity.
     const QUERY_INITIAL_CAPACITY: usize = 256;
+    /// The limits accepted by Binance.
+    pub const LIMITS: [i64; 8] = [5, 10, 20, 50, 100, 500, 1000, 5000];
+    /// The default limit.
+    pub const LIMIT_DEFAULT: i64 = 100;
 
     ///
     /// A shortcut constructor.
@@ -25,15 +33,48 @@ impl Query {
             limit,
         }
     }
+
+    ///
+    /// Checks that the limit is one of `Query::LIMITS`, since Binance does not accept the other
+    /// values.
+    ///
+    pub fn validate(&self) -> Result<(), Error> {
+        match self.limit {
+            Some(limit) if !Self::LIMITS.contains(&limit) => Err(Error::InvalidRequest(format!(
+                "depth limit {} is not one of {:?}",
+                limit,
+                Self::LIMITS
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    ///
+    /// The request weight, which depends on the limit:
+    ///
+    /// - up to 100: 5
+    /// - 500: 25
+    /// - 1000: 50
+    /// - 5000: 250
+    ///
+    pub fn weight(&self) -> u32 {
+        match self.limit.unwrap_or(Self::LIMIT_DEFAULT) {
+            limit if limit <= 100 => 5,
+            limit if limit <= 500 => 25,
+            limit if limit <= 1000 => 50,
+            _ => 250,
+        }
+    }
 }
 
 impl ToString for Query {
     fn to_string(&self) -> String {
-        let mut params = String::with_capacity(Self::QUERY_INITIAL_CAPACITY);
-        params += &format!("symbol={}", self.symbol.to_owned());
+        let mut params =
+            form_urlencoded::Serializer::new(String::with_capacity(Self::QUERY_INITIAL_CAPACITY));
+        params.append_pair("symbol", self.symbol.as_str());
         if let Some(limit) = self.limit {
-            params += &format!("&limit={}", limit.to_string());
+            params.append_pair("limit", &limit.to_string());
         }
-        params
+        params.finish()
     }
 }
@@ -2,17 +2,76 @@
 //! A single depth element.
 //!
 
-use rust_decimal::Decimal;
+use std::fmt;
+
+use serde::de;
 use serde::Deserialize;
+use serde::Deserializer;
+
+use crate::http_api_v3::data::serde::parse_price;
+use crate::http_api_v3::data::serde::PriceType;
+use crate::http_api_v3::data::serde::StringOrNumber;
 
 ///
 /// A single depth element.
 ///
-#[derive(Debug, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
+/// Binance sends it as a `["price", "quantity"]` array.
+///
+#[derive(Debug, Clone)]
 pub struct DepthElement {
     /// The depth level price.
-    pub price: Decimal,
+    pub price: PriceType,
     /// The depth level quantity in secondary token.
-    pub quantity: Decimal,
+    pub quantity: PriceType,
+}
+
+impl DepthElement {
+    ///
+    /// The depth level volume in primary token, that is, `price * quantity`.
+    ///
+    pub fn notional(&self) -> PriceType {
+        self.price * self.quantity
+    }
+}
+
+impl<'de> Deserialize<'de> for DepthElement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(DepthElementVisitor)
+    }
+}
+
+///
+/// The `[price, quantity]` array visitor.
+///
+struct DepthElementVisitor;
+
+impl<'de> de::Visitor<'de> for DepthElementVisitor {
+    type Value = DepthElement;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a `[price, quantity]` array")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let price: StringOrNumber = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let quantity: StringOrNumber = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(3, &self));
+        }
+
+        Ok(DepthElement {
+            price: parse_price(price.into_string().as_str()).map_err(de::Error::custom)?,
+            quantity: parse_price(quantity.into_string().as_str()).map_err(de::Error::custom)?,
+        })
+    }
 }
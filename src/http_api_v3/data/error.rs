@@ -2,6 +2,8 @@
 //! The Binance error response.
 //!
 
+use std::fmt;
+
 use serde::Deserialize;
 
 ///
@@ -15,3 +17,27 @@ pub struct Error {
     /// The Binance error message.
     pub msg: String,
 }
+
+impl Error {
+    ///
+    /// The Binance error code, e.g. `-1121` for an invalid symbol.
+    ///
+    pub fn code(&self) -> i64 {
+        self.code
+    }
+
+    ///
+    /// The Binance error message.
+    ///
+    pub fn msg(&self) -> &str {
+        self.msg.as_str()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.code, self.msg)
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,34 @@
+//!
+//! The exchange info exchange-wide filter.
+//!
+
+use serde::Deserialize;
+
+///
+/// The exchange-wide filter, which applies to all symbols.
+///
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "filterType")]
+pub enum Filter {
+    /// The maximum number of open orders on the account.
+    #[serde(rename_all = "camelCase")]
+    ExchangeMaxNumOrders {
+        /// The maximum number of orders.
+        max_num_orders: i64,
+    },
+    /// The maximum number of open stop-loss and take-profit orders on the account.
+    #[serde(rename_all = "camelCase")]
+    ExchangeMaxNumAlgoOrders {
+        /// The maximum number of algo orders.
+        max_num_algo_orders: i64,
+    },
+    /// The maximum number of open iceberg orders on the account.
+    #[serde(rename_all = "camelCase")]
+    ExchangeMaxNumIcebergOrders {
+        /// The maximum number of iceberg orders.
+        max_num_iceberg_orders: i64,
+    },
+    /// Fallback for all other variants.
+    #[serde(other)]
+    Other,
+}
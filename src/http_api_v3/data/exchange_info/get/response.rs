@@ -2,9 +2,14 @@
 //! The exchange info GET response.
 //!
 
+use std::collections::HashMap;
+
+use chrono::prelude::*;
 use serde::Deserialize;
 
+use crate::http_api_v3::data::exchange_info::filter::Filter;
 use crate::http_api_v3::data::exchange_info::symbol::Symbol;
+use crate::http_api_v3::data::rate_limit::RateLimit;
 
 ///
 /// The `https://www.binance.com/api/v3/exchangeInfo` GET response.
@@ -16,6 +21,106 @@ pub struct Response {
     pub timezone: String,
     /// The server time in milliseconds since Unix epoch.
     pub server_time: i64,
+    /// The request rate limits.
+    pub rate_limits: Vec<RateLimit>,
+    /// The exchange-wide filters.
+    #[serde(default)]
+    pub exchange_filters: Vec<Filter>,
     /// The trading symbol data.
     pub symbols: Vec<Symbol>,
 }
+
+impl Response {
+    ///
+    /// The server time as a date and time.
+    ///
+    pub fn server_datetime(&self) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(self.server_time)
+            .single()
+            .expect("Binance timestamps are within the date and time range")
+    }
+
+    ///
+    /// Finds the symbol data by the symbol name, e.g. `BTCUSDT`.
+    ///
+    /// Scans the symbols list, so use `into_symbol_map` for repeated lookups.
+    ///
+    pub fn symbol(&self, name: &str) -> Option<&Symbol> {
+        self.symbols.iter().find(|symbol| symbol.symbol == name)
+    }
+
+    ///
+    /// Converts the symbols list into a map indexed by the symbol name.
+    ///
+    pub fn into_symbol_map(self) -> HashMap<String, Symbol> {
+        self.symbols
+            .into_iter()
+            .map(|symbol| (symbol.symbol.clone(), symbol))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use crate::http_api_v3::data::exchange_info::filter::Filter as ExchangeFilter;
+    use crate::http_api_v3::data::exchange_info::symbol::filter::Filter;
+    use crate::http_api_v3::data::exchange_info::symbol::status::Status;
+    use crate::http_api_v3::data::rate_limit::interval::RateLimitInterval;
+    use crate::http_api_v3::data::rate_limit::r#type::RateLimitType;
+
+    use super::Response;
+
+    const FIXTURE: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/exchange_info.json"
+    ));
+
+    #[test]
+    fn snapshot_is_parsed() {
+        let response: Response = serde_json::from_str(FIXTURE).expect("Valid fixture");
+
+        assert_eq!(response.timezone, "UTC");
+        assert_eq!(response.server_time, 1_699_999_999_123);
+        assert_eq!(
+            response.server_datetime().timestamp_millis(),
+            1_699_999_999_123
+        );
+
+        assert_eq!(response.rate_limits.len(), 4);
+        let weight = response.rate_limits[0];
+        assert_eq!(weight.rate_limit_type, RateLimitType::RequestWeight);
+        assert_eq!(weight.interval, RateLimitInterval::Minute);
+        assert_eq!(weight.interval_num, 1);
+        assert_eq!(weight.limit, 6000);
+        assert_eq!(response.rate_limits[1].window().as_secs(), 10);
+
+        assert_eq!(
+            response.exchange_filters,
+            vec![
+                ExchangeFilter::ExchangeMaxNumOrders {
+                    max_num_orders: 1000
+                },
+                ExchangeFilter::ExchangeMaxNumAlgoOrders {
+                    max_num_algo_orders: 200
+                },
+            ]
+        );
+
+        let btcusdt = response.symbol("BTCUSDT").expect("Symbol exists");
+        assert_eq!(btcusdt.status, Status::Trading);
+        assert_eq!(btcusdt.filters.len(), 9);
+        assert!(btcusdt.filters.contains(&Filter::Notional {
+            min_notional: Decimal::new(5, 0),
+            apply_min_to_market: true,
+            max_notional: Decimal::new(9_000_000, 0),
+            apply_max_to_market: false,
+            avg_price_mins: 5,
+        }));
+        assert!(btcusdt.filters.contains(&Filter::Other));
+
+        let symbols = response.into_symbol_map();
+        assert_eq!(symbols["ETHBTC"].status, Status::Break);
+    }
+}
@@ -2,5 +2,6 @@
 //! The exchange info.
 //!
 
+pub mod filter;
 pub mod get;
 pub mod symbol;
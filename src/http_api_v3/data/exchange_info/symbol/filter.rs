@@ -4,7 +4,11 @@
 
 use rust_decimal::Decimal;
 use serde::Deserialize;
+use thiserror::Error;
 
+use crate::http_api_v3::data::order_type::OrderType;
+
+#[allow(missing_docs)]
 #[derive(Debug, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "filterType")]
 pub enum Filter {
@@ -32,6 +36,20 @@ pub enum Filter {
         apply_to_market: bool,
         avg_price_mins: i64,
     },
+    /// The order notional range, which supersedes `MIN_NOTIONAL`.
+    #[serde(rename_all = "camelCase")]
+    Notional {
+        /// The minimal notional.
+        min_notional: Decimal,
+        /// If the minimum applies to market orders.
+        apply_min_to_market: bool,
+        /// The maximal notional.
+        max_notional: Decimal,
+        /// If the maximum applies to market orders.
+        apply_max_to_market: bool,
+        /// The number of minutes the average price for market orders is calculated over.
+        avg_price_mins: i64,
+    },
     #[serde(rename_all = "camelCase")]
     IcebergParts { limit: i64 },
     #[serde(rename_all = "camelCase")]
@@ -47,3 +65,37 @@ pub enum Filter {
     #[serde(other)]
     Other,
 }
+
+///
+/// The symbol filter violation, which would make Binance reject the order.
+///
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum FilterError {
+    /// The order type is not allowed for the symbol.
+    #[error("order type {0} is not allowed")]
+    OrderType(OrderType),
+    /// The order price is below the `PRICE_FILTER` minimum.
+    #[error("price {0} is below the minimum of {1}")]
+    MinPrice(Decimal, Decimal),
+    /// The order price is above the `PRICE_FILTER` maximum.
+    #[error("price {0} is above the maximum of {1}")]
+    MaxPrice(Decimal, Decimal),
+    /// The order price is not a multiple of the `PRICE_FILTER` tick size.
+    #[error("price {0} is not a multiple of the tick size {1}")]
+    TickSize(Decimal, Decimal),
+    /// The order quantity is below the `LOT_SIZE` or `MARKET_LOT_SIZE` minimum.
+    #[error("quantity {0} is below the minimum of {1}")]
+    MinQuantity(Decimal, Decimal),
+    /// The order quantity is above the `LOT_SIZE` or `MARKET_LOT_SIZE` maximum.
+    #[error("quantity {0} is above the maximum of {1}")]
+    MaxQuantity(Decimal, Decimal),
+    /// The order quantity is not a multiple of the `LOT_SIZE` or `MARKET_LOT_SIZE` step size.
+    #[error("quantity {0} is not a multiple of the step size {1}")]
+    StepSize(Decimal, Decimal),
+    /// The order notional, that is, `price * quantity`, is below the minimum.
+    #[error("notional {0} is below the minimum of {1}")]
+    MinNotional(Decimal, Decimal),
+    /// The order notional, that is, `price * quantity`, is above the maximum.
+    #[error("notional {0} is above the maximum of {1}")]
+    MaxNotional(Decimal, Decimal),
+}
@@ -12,6 +12,7 @@ use crate::http_api_v3::data::order_type::OrderType;
 use crate::http_api_v3::data::permission::Permission;
 
 use self::filter::Filter;
+use self::filter::FilterError;
 use self::status::Status;
 
 ///
@@ -42,6 +43,12 @@ pub struct Symbol {
     pub filters: Vec<Filter>,
     /// The allowed trading methods like spot, margin, etc.
     pub permissions: Vec<Permission>,
+    /// If spot trading is allowed for the symbol.
+    #[serde(default)]
+    pub is_spot_trading_allowed: bool,
+    /// If margin trading is allowed for the symbol.
+    #[serde(default)]
+    pub is_margin_trading_allowed: bool,
 }
 
 impl Symbol {
@@ -52,6 +59,13 @@ impl Symbol {
         matches!(self.status, Status::Trading)
     }
 
+    ///
+    /// If the order type is allowed for the symbol, e.g. to check it before placing an order.
+    ///
+    pub fn supports_order_type(&self, order_type: &OrderType) -> bool {
+        self.order_types.contains(order_type)
+    }
+
     ///
     /// If margin trading is allowed for the symbol.
     ///
@@ -97,4 +111,343 @@ impl Symbol {
         }
         None
     }
+
+    ///
+    /// Checks that the order notional, that is, `price * quantity`, satisfies the `MIN_NOTIONAL`
+    /// and `NOTIONAL` filters, since Binance rejects such orders with the `-1013` error.
+    ///
+    /// The zero maximal notional is treated as disabled.
+    ///
+    pub fn check_notional(&self, price: Decimal, quantity: Decimal) -> Result<(), FilterError> {
+        let notional = price * quantity;
+        for filter in self.filters.iter() {
+            match filter {
+                Filter::MinNotional { min_notional, .. } if notional < *min_notional => {
+                    return Err(FilterError::MinNotional(notional, *min_notional));
+                }
+                Filter::Notional { min_notional, .. } if notional < *min_notional => {
+                    return Err(FilterError::MinNotional(notional, *min_notional));
+                }
+                Filter::Notional { max_notional, .. }
+                    if *max_notional > Decimal::new(0, 0) && notional > *max_notional =>
+                {
+                    return Err(FilterError::MaxNotional(notional, *max_notional));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Checks the order against the order types and the `PRICE_FILTER`, `LOT_SIZE`,
+    /// `MARKET_LOT_SIZE`, `MIN_NOTIONAL`, and `NOTIONAL` filters, and returns all the violations
+    /// at once, e.g. to show them in an order form.
+    ///
+    /// The `price` is `None` for the market orders, whose price and notional are not checked.
+    /// The zero filter limits are treated as disabled.
+    ///
+    pub fn validate_order(
+        &self,
+        order_type: OrderType,
+        price: Option<Decimal>,
+        quantity: Decimal,
+    ) -> Result<(), Vec<FilterError>> {
+        let zero = Decimal::new(0, 0);
+        let is_market = matches!(order_type, OrderType::Market);
+
+        let mut errors = Vec::new();
+        if !self.supports_order_type(&order_type) {
+            errors.push(FilterError::OrderType(order_type));
+        }
+        for filter in self.filters.iter() {
+            match filter {
+                Filter::PriceFilter {
+                    min_price,
+                    max_price,
+                    tick_size,
+                } => {
+                    let price = match price {
+                        Some(price) => price,
+                        None => continue,
+                    };
+                    if *min_price > zero && price < *min_price {
+                        errors.push(FilterError::MinPrice(price, *min_price));
+                    }
+                    if *max_price > zero && price > *max_price {
+                        errors.push(FilterError::MaxPrice(price, *max_price));
+                    }
+                    if Self::align_down(price, *tick_size) != price {
+                        errors.push(FilterError::TickSize(price, *tick_size));
+                    }
+                }
+                Filter::LotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => Self::validate_quantity(quantity, *min_qty, *max_qty, *step_size, &mut errors),
+                Filter::MarketLotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } if is_market => {
+                    Self::validate_quantity(quantity, *min_qty, *max_qty, *step_size, &mut errors)
+                }
+                _ => {}
+            }
+        }
+        if let Some(price) = price {
+            let notional = price * quantity;
+            for filter in self.filters.iter() {
+                match filter {
+                    Filter::MinNotional { min_notional, .. } if notional < *min_notional => {
+                        errors.push(FilterError::MinNotional(notional, *min_notional));
+                    }
+                    Filter::Notional {
+                        min_notional,
+                        max_notional,
+                        ..
+                    } => {
+                        if notional < *min_notional {
+                            errors.push(FilterError::MinNotional(notional, *min_notional));
+                        }
+                        if *max_notional > zero && notional > *max_notional {
+                            errors.push(FilterError::MaxNotional(notional, *max_notional));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    ///
+    /// Splits the `total` quantity into the child order quantities at `price`, which are aligned
+    /// to the `LOT_SIZE` step, are at most `max_child`, and satisfy the quantity and notional
+    /// limits.
+    ///
+    /// The minimum chunk is the larger of the `LOT_SIZE` minimum quantity and the minimum notional
+    /// divided by `price`, rounded up to the step.
+    ///
+    /// The chunks sum up to `total` rounded down to the step. If the remainder is below the
+    /// minimum chunk, it is merged into the last chunk, which is then split in two halves. If even
+    /// the halves are below the minimum chunk, the remainder is dropped, so the chunks sum up to
+    /// less than `total`, and the caller must handle the difference, e.g. by adding it to the next
+    /// order. Returns no chunks if `total` is below the minimum chunk.
+    ///
+    pub fn split_quantity(
+        &self,
+        total: Decimal,
+        max_child: Decimal,
+        price: Decimal,
+    ) -> Vec<Decimal> {
+        let zero = Decimal::new(0, 0);
+
+        let mut step = zero;
+        let mut lower = zero;
+        let mut upper = max_child;
+        for filter in self.filters.iter() {
+            match filter {
+                Filter::LotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => {
+                    step = *step_size;
+                    lower = lower.max(*min_qty);
+                    if *max_qty > zero {
+                        upper = upper.min(*max_qty);
+                    }
+                }
+                Filter::MinNotional { min_notional, .. } if price > zero => {
+                    lower = lower.max(Self::align_up(*min_notional / price, step));
+                }
+                Filter::Notional {
+                    min_notional,
+                    max_notional,
+                    ..
+                } if price > zero => {
+                    lower = lower.max(Self::align_up(*min_notional / price, step));
+                    if *max_notional > zero {
+                        upper = upper.min(*max_notional / price);
+                    }
+                }
+                _ => {}
+            }
+        }
+        // the step may be found after the notional filters
+        lower = Self::align_up(lower, step);
+        let child = Self::align_down(upper, step);
+        let total = Self::align_down(total, step);
+        if child <= zero || child < lower || total < lower {
+            return vec![];
+        }
+
+        let mut chunks = Vec::new();
+        let mut remainder = total;
+        while remainder >= child {
+            chunks.push(child);
+            remainder -= child;
+        }
+        if remainder == zero {
+            return chunks;
+        }
+        if remainder >= lower {
+            chunks.push(remainder);
+            return chunks;
+        }
+
+        let combined = child + remainder;
+        let first = Self::align_down(combined / Decimal::new(2, 0), step);
+        if first >= lower {
+            chunks.pop();
+            chunks.push(combined - first);
+            chunks.push(first);
+        }
+        chunks
+    }
+
+    ///
+    /// Checks the `quantity` against the quantity range and step, adding the violations to
+    /// `errors`.
+    ///
+    fn validate_quantity(
+        quantity: Decimal,
+        min_qty: Decimal,
+        max_qty: Decimal,
+        step_size: Decimal,
+        errors: &mut Vec<FilterError>,
+    ) {
+        let zero = Decimal::new(0, 0);
+        if quantity < min_qty {
+            errors.push(FilterError::MinQuantity(quantity, min_qty));
+        }
+        if max_qty > zero && quantity > max_qty {
+            errors.push(FilterError::MaxQuantity(quantity, max_qty));
+        }
+        if Self::align_down(quantity, step_size) != quantity {
+            errors.push(FilterError::StepSize(quantity, step_size));
+        }
+    }
+
+    ///
+    /// Rounds `value` down to the multiple of `step`. Returns `value` as is if `step` is zero.
+    ///
+    fn align_down(value: Decimal, step: Decimal) -> Decimal {
+        if step > Decimal::new(0, 0) {
+            (value / step).floor() * step
+        } else {
+            value
+        }
+    }
+
+    ///
+    /// Rounds `value` up to the multiple of `step`. Returns `value` as is if `step` is zero.
+    ///
+    fn align_up(value: Decimal, step: Decimal) -> Decimal {
+        if step > Decimal::new(0, 0) {
+            (value / step).ceil() * step
+        } else {
+            value
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::Filter;
+    use super::FilterError;
+    use super::Symbol;
+
+    fn symbol() -> Symbol {
+        serde_json::from_str(
+            r#"{
+                "symbol": "BTCUSDT",
+                "status": "TRADING",
+                "baseAsset": "BTC",
+                "baseAssetPrecision": 8,
+                "quoteAsset": "USDT",
+                "quotePrecision": 8,
+                "orderTypes": ["LIMIT", "MARKET"],
+                "icebergAllowed": true,
+                "filters": [
+                    {
+                        "filterType": "LOT_SIZE",
+                        "minQty": "0.10",
+                        "maxQty": "100.00",
+                        "stepSize": "0.01"
+                    },
+                    {
+                        "filterType": "MIN_NOTIONAL",
+                        "minNotional": "1.00",
+                        "applyToMarket": true,
+                        "avgPriceMins": 5
+                    }
+                ],
+                "permissions": ["SPOT"]
+            }"#,
+        )
+        .expect("Valid symbol")
+    }
+
+    #[test]
+    fn split_quantity_merges_remainder_below_minimum() {
+        let chunks = symbol().split_quantity(
+            Decimal::new(205, 2),
+            Decimal::new(1, 0),
+            Decimal::new(100, 0),
+        );
+        assert_eq!(
+            chunks,
+            vec![Decimal::new(1, 0), Decimal::new(53, 2), Decimal::new(52, 2)]
+        );
+    }
+
+    #[test]
+    fn split_quantity_drops_remainder_below_minimum_halves() {
+        let chunks = symbol().split_quantity(
+            Decimal::new(32, 2),
+            Decimal::new(15, 2),
+            Decimal::new(100, 0),
+        );
+        assert_eq!(chunks, vec![Decimal::new(15, 2), Decimal::new(15, 2)]);
+    }
+
+    #[test]
+    fn split_quantity_respects_price_derived_minimum() {
+        let chunks =
+            symbol().split_quantity(Decimal::new(5, 1), Decimal::new(1, 0), Decimal::new(1, 0));
+        assert!(chunks.is_empty());
+    }
+
+    #[test]
+    fn check_notional_ignores_zero_maximum() {
+        let mut symbol = symbol();
+        symbol.filters = vec![Filter::Notional {
+            min_notional: Decimal::new(5, 0),
+            apply_min_to_market: true,
+            max_notional: Decimal::new(0, 0),
+            apply_max_to_market: false,
+            avg_price_mins: 5,
+        }];
+        assert_eq!(
+            symbol.check_notional(Decimal::new(1_000_000, 0), Decimal::new(1, 0)),
+            Ok(())
+        );
+        assert_eq!(
+            symbol.check_notional(Decimal::new(1, 0), Decimal::new(1, 0)),
+            Err(FilterError::MinNotional(
+                Decimal::new(1, 0),
+                Decimal::new(5, 0)
+            ))
+        );
+    }
 }
@@ -0,0 +1,6 @@
+//!
+//! The historical trades GET.
+//!
+
+pub mod request;
+pub mod response;
@@ -0,0 +1,9 @@
+//!
+//! The historical trades GET response.
+//!
+
+///
+/// The `https://www.binance.com/api/v3/historicalTrades` GET response, which is the same as the
+/// recent trades one.
+///
+pub type Response = crate::http_api_v3::data::trades::get::response::Response;
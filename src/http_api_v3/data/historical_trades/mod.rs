@@ -0,0 +1,5 @@
+//!
+//! The historical trades.
+//!
+
+pub mod get;
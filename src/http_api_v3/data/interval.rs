@@ -29,31 +29,31 @@ pub enum Interval {
     #[serde(rename = "30m")]
     Minute30,
     /// The 1 hour interval.
-    #[serde(rename = "1H")]
+    #[serde(rename = "1H", alias = "1h")]
     Hour1,
     /// The 2 hours interval.
-    #[serde(rename = "2H")]
+    #[serde(rename = "2H", alias = "2h")]
     Hour2,
     /// The 4 hours interval.
-    #[serde(rename = "4H")]
+    #[serde(rename = "4H", alias = "4h")]
     Hour4,
     /// The 6 hours interval.
-    #[serde(rename = "6H")]
+    #[serde(rename = "6H", alias = "6h")]
     Hour6,
     /// The 8 hours interval.
-    #[serde(rename = "8H")]
+    #[serde(rename = "8H", alias = "8h")]
     Hour8,
     /// The 12 hours interval.
-    #[serde(rename = "12H")]
+    #[serde(rename = "12H", alias = "12h")]
     Hour12,
     /// The 1 day interval.
-    #[serde(rename = "1D")]
+    #[serde(rename = "1D", alias = "1d")]
     Day1,
     /// The 3 days interval.
-    #[serde(rename = "3D")]
+    #[serde(rename = "3D", alias = "3d")]
     Day3,
     /// The 1 week interval.
-    #[serde(rename = "1W")]
+    #[serde(rename = "1W", alias = "1w")]
     Week1,
     /// The 1 month interval.
     #[serde(rename = "1M")]
@@ -0,0 +1,151 @@
+//!
+//! The klines in the columnar form.
+//!
+
+use crate::http_api_v3::data::klines::kline::Kline;
+use crate::http_api_v3::data::serde::PriceType;
+
+///
+/// The klines in the columnar form, that is, a vector per kline field, e.g. to be fed into the
+/// analysis libraries.
+///
+/// Created from the klines response with `Candles::from` or `IntoColumns::into_columns`.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Candles {
+    /// The kline open times in milliseconds since Unix epoch.
+    pub open_time: Vec<i64>,
+    /// The kline open prices.
+    pub open: Vec<PriceType>,
+    /// The kline high prices.
+    pub high: Vec<PriceType>,
+    /// The kline low prices.
+    pub low: Vec<PriceType>,
+    /// The kline close prices.
+    pub close: Vec<PriceType>,
+    /// The kline volumes in secondary token.
+    pub volume: Vec<PriceType>,
+    /// The kline close times in milliseconds since Unix epoch.
+    pub close_time: Vec<i64>,
+    /// The kline volumes in primary token.
+    pub quote_asset_volume: Vec<PriceType>,
+    /// The numbers of trades executed within the klines.
+    pub number_of_trades: Vec<i64>,
+    /// The taker buy volumes in secondary token.
+    pub taker_buy_base_asset_volume: Vec<PriceType>,
+    /// The taker buy volumes in primary token.
+    pub taker_buy_quote_asset_volume: Vec<PriceType>,
+}
+
+impl Candles {
+    ///
+    /// The number of klines.
+    ///
+    pub fn len(&self) -> usize {
+        self.open_time.len()
+    }
+
+    ///
+    /// If there are no klines.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.open_time.is_empty()
+    }
+}
+
+///
+/// The conversion of the klines response into the columnar form.
+///
+pub trait IntoColumns {
+    ///
+    /// Converts the klines into a vector per kline field.
+    ///
+    fn into_columns(self) -> Candles;
+}
+
+impl IntoColumns for Vec<Kline> {
+    fn into_columns(self) -> Candles {
+        Candles::from(self)
+    }
+}
+
+impl From<Vec<Kline>> for Candles {
+    fn from(klines: Vec<Kline>) -> Self {
+        let capacity = klines.len();
+        let mut candles = Self {
+            open_time: Vec::with_capacity(capacity),
+            open: Vec::with_capacity(capacity),
+            high: Vec::with_capacity(capacity),
+            low: Vec::with_capacity(capacity),
+            close: Vec::with_capacity(capacity),
+            volume: Vec::with_capacity(capacity),
+            close_time: Vec::with_capacity(capacity),
+            quote_asset_volume: Vec::with_capacity(capacity),
+            number_of_trades: Vec::with_capacity(capacity),
+            taker_buy_base_asset_volume: Vec::with_capacity(capacity),
+            taker_buy_quote_asset_volume: Vec::with_capacity(capacity),
+        };
+
+        for kline in klines.into_iter() {
+            candles.open_time.push(kline.open_time);
+            candles.open.push(kline.open);
+            candles.high.push(kline.high);
+            candles.low.push(kline.low);
+            candles.close.push(kline.close);
+            candles.volume.push(kline.volume);
+            candles.close_time.push(kline.close_time);
+            candles.quote_asset_volume.push(kline.quote_asset_volume);
+            candles.number_of_trades.push(kline.number_of_trades);
+            candles
+                .taker_buy_base_asset_volume
+                .push(kline.taker_buy_base_asset_volume);
+            candles
+                .taker_buy_quote_asset_volume
+                .push(kline.taker_buy_quote_asset_volume);
+        }
+        candles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http_api_v3::data::klines::kline::Kline;
+    use crate::http_api_v3::data::serde::PriceType;
+
+    use super::IntoColumns;
+
+    fn kline(open_time: i64, close: &str) -> Kline {
+        let close: PriceType = close.parse().expect("Valid price");
+        Kline {
+            open_time,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: PriceType::default(),
+            close_time: open_time + 59_999,
+            quote_asset_volume: PriceType::default(),
+            number_of_trades: 1,
+            taker_buy_base_asset_volume: PriceType::default(),
+            taker_buy_quote_asset_volume: PriceType::default(),
+            ignore: PriceType::default(),
+        }
+    }
+
+    #[test]
+    fn klines_are_transposed_in_order() {
+        let candles = vec![kline(0, "10"), kline(60_000, "11")].into_columns();
+
+        assert_eq!(candles.len(), 2);
+        assert_eq!(candles.open_time, vec![0, 60_000]);
+        assert_eq!(candles.close_time, vec![59_999, 119_999]);
+        assert_eq!(
+            candles.close,
+            vec![
+                "10".parse::<PriceType>().expect("Valid price"),
+                "11".parse::<PriceType>().expect("Valid price"),
+            ]
+        );
+        assert_eq!(candles.number_of_trades, vec![1, 1]);
+    }
+}
@@ -2,11 +2,14 @@
 //! The klines GET request.
 //!
 
+use crate::error::Error;
 use crate::http_api_v3::data::interval::Interval;
+use crate::http_api_v3::data::time_range;
 
 ///
 /// The `https://www.binance.com/api/v3/klines` GET request query.
 ///
+#[derive(Debug)]
 pub struct Query {
     /// The symbol name.
     pub symbol: String,
@@ -18,6 +21,8 @@ pub struct Query {
     pub end_time: Option<i64>,
     /// The maximum number of klines to get.
     pub limit: Option<usize>,
+    /// The time zone, which shifts the interval boundaries, e.g. `+08:00`. UTC by default.
+    pub time_zone: Option<String>,
 }
 
 impl Query {
@@ -40,24 +45,43 @@ impl Query {
             start_time,
             end_time,
             limit,
+            time_zone: None,
         }
     }
+
+    ///
+    /// Checks that the time range is not inverted.
+    ///
+    pub fn validate(&self) -> Result<(), Error> {
+        time_range::validate(self.start_time, self.end_time, None)
+    }
+
+    ///
+    /// The request weight.
+    ///
+    pub fn weight(&self) -> u32 {
+        2
+    }
 }
 
 impl ToString for Query {
     fn to_string(&self) -> String {
-        let mut params = String::with_capacity(Self::QUERY_INITIAL_CAPACITY);
-        params += &format!("symbol={}", self.symbol.to_owned());
-        params += &format!("&interval={}", self.interval.to_string());
+        let mut params =
+            form_urlencoded::Serializer::new(String::with_capacity(Self::QUERY_INITIAL_CAPACITY));
+        params.append_pair("symbol", self.symbol.as_str());
+        params.append_pair("interval", &self.interval.to_string());
         if let Some(start_time) = self.start_time {
-            params += &format!("&startTime={}", start_time);
+            params.append_pair("startTime", &start_time.to_string());
         }
         if let Some(end_time) = self.end_time {
-            params += &format!("&endTime={}", end_time);
+            params.append_pair("endTime", &end_time.to_string());
         }
         if let Some(limit) = self.limit {
-            params += &format!("&limit={}", limit);
+            params.append_pair("limit", &limit.to_string());
+        }
+        if let Some(ref time_zone) = self.time_zone {
+            params.append_pair("timeZone", time_zone.as_str());
         }
-        params
+        params.finish()
     }
 }
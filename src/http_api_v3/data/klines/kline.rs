@@ -2,42 +2,70 @@
 //! A single kline.
 //!
 
-use rust_decimal::Decimal;
+use std::fmt;
+
+use chrono::prelude::*;
+use serde::de;
 use serde::Deserialize;
+use serde::Deserializer;
+
+use crate::http_api_v3::data::serde::parse_price;
+use crate::http_api_v3::data::serde::PriceType;
+use crate::http_api_v3::data::serde::StringOrNumber;
 
 ///
 /// A single kline.
 ///
-#[derive(Debug, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
+/// Binance sends it as a `[openTime, "open", "high", "low", "close", "volume", closeTime, ...]`
+/// array.
+///
+#[derive(Debug, Clone)]
 pub struct Kline {
     /// The kline open time in milliseconds since Unix epoch.
     pub open_time: i64,
     /// The kline open price.
-    pub open: Decimal,
+    pub open: PriceType,
     /// The kline high price.
-    pub high: Decimal,
+    pub high: PriceType,
     /// The kline low price.
-    pub low: Decimal,
+    pub low: PriceType,
     /// The kline close price.
-    pub close: Decimal,
+    pub close: PriceType,
     /// The kline volume in secondary token.
-    pub volume: Decimal,
+    pub volume: PriceType,
     /// The kline open time in milliseconds since Unix epoch.
     pub close_time: i64,
     /// The kline volume in primary token.
-    pub quote_asset_volume: Decimal,
+    pub quote_asset_volume: PriceType,
     /// The number of trades executed within the kline.
     pub number_of_trades: i64,
     /// The taker buy volume in secondary token.
-    pub taker_buy_base_asset_volume: Decimal,
+    pub taker_buy_base_asset_volume: PriceType,
     /// The taker buy volume in primary token.
-    pub taker_buy_quote_asset_volume: Decimal,
-    /// The unknown value.
-    pub ignore: Decimal,
+    pub taker_buy_quote_asset_volume: PriceType,
+    /// The unknown value. Zero if Binance omits it.
+    pub ignore: PriceType,
 }
 
 impl Kline {
+    ///
+    /// The kline open time as a date and time.
+    ///
+    pub fn open_datetime(&self) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(self.open_time)
+            .single()
+            .expect("Binance timestamps are within the date and time range")
+    }
+
+    ///
+    /// The kline close time as a date and time.
+    ///
+    pub fn close_datetime(&self) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(self.close_time)
+            .single()
+            .expect("Binance timestamps are within the date and time range")
+    }
+
     ///
     /// If the kline is green, that is, `close >= open`.
     ///
@@ -55,21 +83,159 @@ impl Kline {
     ///
     /// The average of low and high.
     ///
-    pub fn middle(&self) -> Decimal {
-        (self.low + self.high) / Decimal::new(2, 0)
+    pub fn middle(&self) -> PriceType {
+        (self.low + self.high) / PriceType::from(2u8)
     }
 
     ///
     /// The average of open and close.
     ///
-    pub fn middle_body(&self) -> Decimal {
-        (self.open + self.close) / Decimal::new(2, 0)
+    pub fn middle_body(&self) -> PriceType {
+        (self.open + self.close) / PriceType::from(2u8)
     }
 
     ///
     /// The average of open, high, low, and close.
     ///
-    pub fn average(&self) -> Decimal {
-        (self.low + self.open + self.close + self.high) / Decimal::new(4, 0)
+    pub fn average(&self) -> PriceType {
+        (self.low + self.open + self.close + self.high) / PriceType::from(4u8)
+    }
+}
+
+impl<'de> Deserialize<'de> for Kline {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(KlineVisitor)
+    }
+}
+
+///
+/// The kline array visitor.
+///
+struct KlineVisitor;
+
+impl KlineVisitor {
+    ///
+    /// Reads the required array element at `index`.
+    ///
+    fn element<'de, A, T>(&self, seq: &mut A, index: usize) -> Result<T, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+        T: Deserialize<'de>,
+    {
+        seq.next_element()?
+            .ok_or_else(|| de::Error::invalid_length(index, self))
+    }
+
+    ///
+    /// Reads the required integer array element at `index`, e.g. a time or the number of trades,
+    /// which may be sent as either a string or a number.
+    ///
+    fn integer<'de, A>(&self, seq: &mut A, index: usize) -> Result<i64, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let value: StringOrNumber = self.element(seq, index)?;
+        let value = value.into_string();
+        value
+            .parse::<i64>()
+            .map_err(|error| de::Error::custom(format!("{}: {}", value, error)))
+    }
+
+    ///
+    /// Reads the required price or quantity array element at `index`, which may be sent as
+    /// either a string or a number.
+    ///
+    fn price<'de, A>(&self, seq: &mut A, index: usize) -> Result<PriceType, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let value: StringOrNumber = self.element(seq, index)?;
+        parse_price(value.into_string().as_str()).map_err(de::Error::custom)
+    }
+}
+
+impl<'de> de::Visitor<'de> for KlineVisitor {
+    type Value = Kline;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a kline array of at least 11 elements")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let kline = Kline {
+            open_time: self.integer(&mut seq, 0)?,
+            open: self.price(&mut seq, 1)?,
+            high: self.price(&mut seq, 2)?,
+            low: self.price(&mut seq, 3)?,
+            close: self.price(&mut seq, 4)?,
+            volume: self.price(&mut seq, 5)?,
+            close_time: self.integer(&mut seq, 6)?,
+            quote_asset_volume: self.price(&mut seq, 7)?,
+            number_of_trades: self.integer(&mut seq, 8)?,
+            taker_buy_base_asset_volume: self.price(&mut seq, 9)?,
+            taker_buy_quote_asset_volume: self.price(&mut seq, 10)?,
+            ignore: match seq.next_element::<StringOrNumber>()? {
+                Some(value) => {
+                    parse_price(value.into_string().as_str()).map_err(de::Error::custom)?
+                }
+                None => PriceType::default(),
+            },
+        };
+        while seq.next_element::<de::IgnoredAny>()?.is_some() {}
+
+        Ok(kline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http_api_v3::data::serde::parse_price;
+
+    use super::Kline;
+
+    fn check(kline: Kline) {
+        let price = |value: &str| parse_price(value).expect("Valid price");
+        assert_eq!(kline.open_time, 1_499_040_000_000);
+        assert_eq!(kline.open, price("0.01634790"));
+        assert_eq!(kline.high, price("0.80000000"));
+        assert_eq!(kline.low, price("0.01575800"));
+        assert_eq!(kline.close, price("0.01577100"));
+        assert_eq!(kline.volume, price("148976.11427815"));
+        assert_eq!(kline.close_time, 1_499_644_799_999);
+        assert_eq!(kline.quote_asset_volume, price("2434.19055334"));
+        assert_eq!(kline.number_of_trades, 308);
+        assert_eq!(kline.taker_buy_base_asset_volume, price("1756.87402397"));
+        assert_eq!(kline.taker_buy_quote_asset_volume, price("28.46694368"));
+        assert_eq!(kline.ignore, price("0"));
+    }
+
+    #[test]
+    fn kline_of_strings_is_parsed() {
+        check(
+            serde_json::from_str(
+                r#"[1499040000000, "0.01634790", "0.80000000", "0.01575800", "0.01577100",
+                "148976.11427815", 1499644799999, "2434.19055334", 308, "1756.87402397",
+                "28.46694368", "0"]"#,
+            )
+            .expect("Valid kline"),
+        );
+    }
+
+    #[test]
+    fn kline_of_numbers_is_parsed() {
+        check(
+            serde_json::from_str(
+                r#"["1499040000000", 0.01634790, 0.80000000, 0.01575800, 0.01577100,
+                148976.11427815, "1499644799999", 2434.19055334, "308", 1756.87402397,
+                28.46694368]"#,
+            )
+            .expect("Valid kline"),
+        );
     }
 }
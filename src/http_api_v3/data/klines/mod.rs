@@ -2,5 +2,6 @@
 //! The klines.
 //!
 
+pub mod candles;
 pub mod get;
 pub mod kline;
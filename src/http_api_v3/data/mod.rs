@@ -2,18 +2,76 @@
 //! The Binance API v3 data structures.
 //!
 
+///
+/// Generates the tests of a string enum with the `Unknown(String)` fallback, that is, the
+/// round trip of each `(variant, string)` pair, the `unknown` string fallback, and the empty
+/// string rejection.
+///
+#[cfg(test)]
+macro_rules! string_enum_tests {
+    ($type:ident, $unknown:expr, [$(($variant:expr, $string:expr)),+ $(,)?]) => {
+        fn parse_json(value: &str) -> Result<$type, serde_json::Error> {
+            serde_json::from_value(serde_json::Value::String(value.to_owned()))
+        }
+
+        #[test]
+        fn every_variant_round_trips() {
+            for (value, string) in vec![$(($variant, $string)),+].into_iter() {
+                assert_eq!(value.to_string(), string);
+                assert_eq!(string.parse::<$type>().as_ref(), Ok(&value));
+                assert_eq!(parse_json(string).expect("Valid JSON"), value);
+            }
+        }
+
+        #[test]
+        fn unknown_string_is_kept() {
+            let value = parse_json($unknown).expect("Valid JSON");
+            assert_eq!(value, $type::Unknown($unknown.to_owned()));
+            assert_eq!(value.to_string(), $unknown);
+        }
+
+        #[test]
+        fn empty_string_is_rejected() {
+            assert!("".parse::<$type>().is_err());
+            assert!(parse_json("").is_err());
+        }
+    };
+}
+
 pub mod account;
+pub mod account_commission;
+pub mod agg_trades;
+pub mod all_orders;
+pub mod book_ticker;
 pub mod depth;
 pub mod depth_element;
 pub mod error;
 pub mod exchange_info;
+pub mod historical_trades;
 pub mod interval;
 pub mod klines;
+pub mod my_prevented_matches;
+pub mod my_trades;
+pub mod open_order_list;
 pub mod open_orders;
 pub mod order;
+pub mod order_amend_keep_priority;
+pub mod order_list;
 pub mod order_side;
 pub mod order_status;
 pub mod order_time_in_force;
 pub mod order_type;
 pub mod permission;
+pub mod price_ticker;
+pub mod rate_limit;
+pub mod rate_limit_order;
+pub mod sapi;
+pub mod self_trade_prevention_mode;
+pub mod serde;
+pub mod ticker_24hr;
+pub mod ticker_rolling;
+pub mod ticker_type;
 pub mod time;
+pub mod time_range;
+pub mod trades;
+pub mod user_data_stream;
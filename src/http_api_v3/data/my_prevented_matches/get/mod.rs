@@ -0,0 +1,6 @@
+//!
+//! The account prevented matches GET.
+//!
+
+pub mod request;
+pub mod response;
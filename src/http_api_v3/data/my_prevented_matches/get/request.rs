@@ -0,0 +1,90 @@
+//!
+//! The account prevented matches GET request.
+//!
+
+use std::fmt;
+
+use crate::http_api_v3::signed_query::SignedQuery;
+
+///
+/// The `https://www.binance.com/api/v3/myPreventedMatches` GET request query.
+///
+#[derive(Debug)]
+pub struct Query {
+    /// The symbol name.
+    pub symbol: String,
+    /// The prevented match ID. Either `preventedMatchId` or `orderId` must be sent.
+    pub prevented_match_id: Option<i64>,
+    /// The server-side order ID.
+    pub order_id: Option<i64>,
+    /// The prevented match ID to start from. Only used together with `orderId`.
+    pub from_prevented_match_id: Option<i64>,
+    /// The maximal number of prevented matches. Default is 500, maximum is 1000.
+    pub limit: Option<usize>,
+    /// The allowed time window between the request and response in milliseconds.
+    pub recv_window: Option<i64>,
+    /// The request time in milliseconds, which is set by the client on signing.
+    pub timestamp: i64,
+}
+
+impl Query {
+    /// The query params default capacity.
+    const QUERY_INITIAL_CAPACITY: usize = 256;
+
+    ///
+    /// A shortcut constructor, which finds the prevented matches of the order.
+    ///
+    pub fn new(symbol: &str, order_id: i64) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            prevented_match_id: None,
+            order_id: Some(order_id),
+            from_prevented_match_id: None,
+            limit: None,
+            recv_window: None,
+            timestamp: 0,
+        }
+    }
+
+    ///
+    /// The request weight, which is 2 for a single prevented match and 20 otherwise.
+    ///
+    pub fn weight(&self) -> u32 {
+        if self.prevented_match_id.is_some() {
+            2
+        } else {
+            20
+        }
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut params =
+            form_urlencoded::Serializer::new(String::with_capacity(Self::QUERY_INITIAL_CAPACITY));
+        params.append_pair("symbol", self.symbol.as_str());
+        if let Some(prevented_match_id) = self.prevented_match_id {
+            params.append_pair("preventedMatchId", &prevented_match_id.to_string());
+        }
+        if let Some(order_id) = self.order_id {
+            params.append_pair("orderId", &order_id.to_string());
+        }
+        if let Some(from_prevented_match_id) = self.from_prevented_match_id {
+            params.append_pair("fromPreventedMatchId", &from_prevented_match_id.to_string());
+        }
+        if let Some(limit) = self.limit {
+            params.append_pair("limit", &limit.to_string());
+        }
+        if let Some(recv_window) = self.recv_window {
+            params.append_pair("recvWindow", &recv_window.to_string());
+        }
+        params.append_pair("timestamp", &self.timestamp.to_string());
+        write!(f, "{}", params.finish())
+    }
+}
+
+impl SignedQuery for Query {
+    fn set_timestamp(&mut self, timestamp: i64) {
+        self.timestamp = timestamp;
+    }
+}
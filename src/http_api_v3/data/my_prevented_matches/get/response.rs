@@ -0,0 +1,39 @@
+//!
+//! The account prevented matches GET response.
+//!
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::http_api_v3::data::self_trade_prevention_mode::SelfTradePreventionMode;
+
+///
+/// The `https://www.binance.com/api/v3/myPreventedMatches` GET response.
+///
+pub type Response = Vec<PreventedMatch>;
+
+///
+/// A single match, which has been prevented by the self-trade prevention.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PreventedMatch {
+    /// The symbol name.
+    pub symbol: String,
+    /// The prevented match ID.
+    pub prevented_match_id: i64,
+    /// The taker order ID.
+    pub taker_order_id: i64,
+    /// The maker order ID.
+    pub maker_order_id: i64,
+    /// The trade group ID.
+    pub trade_group_id: i64,
+    /// The self-trade prevention mode, which has expired the orders.
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+    /// The match price.
+    pub price: Decimal,
+    /// The maker order quantity, which has been prevented.
+    pub maker_prevented_quantity: Decimal,
+    /// The prevention time in milliseconds.
+    pub transact_time: i64,
+}
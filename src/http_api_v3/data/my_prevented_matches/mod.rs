@@ -0,0 +1,5 @@
+//!
+//! The account prevented matches.
+//!
+
+pub mod get;
@@ -0,0 +1,6 @@
+//!
+//! The account trades GET.
+//!
+
+pub mod request;
+pub mod response;
@@ -0,0 +1,45 @@
+//!
+//! The account trades GET response.
+//!
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+///
+/// The `https://www.binance.com/api/v3/myTrades` GET response.
+///
+pub type Response = Vec<Trade>;
+
+///
+/// A single account trade.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    /// The symbol name.
+    pub symbol: String,
+    /// The trade ID.
+    pub id: i64,
+    /// The server-side order ID.
+    pub order_id: i64,
+    /// The server-side order list ID. `-1` if the order is not a part of a list.
+    pub order_list_id: i64,
+    /// The trade price.
+    pub price: Decimal,
+    /// The trade quantity in the secondary asset.
+    pub qty: Decimal,
+    /// The trade quantity in the primary asset.
+    pub quote_qty: Decimal,
+    /// The commission amount.
+    pub commission: Decimal,
+    /// The commission asset.
+    pub commission_asset: String,
+    /// The trade time in milliseconds.
+    pub time: i64,
+    /// Whether the account is the buyer.
+    pub is_buyer: bool,
+    /// Whether the account order is the maker.
+    pub is_maker: bool,
+    /// Whether the trade is the best price match.
+    pub is_best_match: bool,
+}
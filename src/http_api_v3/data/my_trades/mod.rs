@@ -0,0 +1,5 @@
+//!
+//! The account trades.
+//!
+
+pub mod get;
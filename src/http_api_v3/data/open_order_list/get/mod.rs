@@ -0,0 +1,6 @@
+//!
+//! The open order list GET.
+//!
+
+pub mod request;
+pub mod response;
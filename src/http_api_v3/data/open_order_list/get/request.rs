@@ -0,0 +1,64 @@
+//!
+//! The open order list GET request.
+//!
+
+use std::fmt;
+
+use crate::http_api_v3::signed_query::SignedQuery;
+
+///
+/// The `https://www.binance.com/api/v3/openOrderList` GET request query.
+///
+#[derive(Debug)]
+pub struct Query {
+    /// The allowed time window between the request and response in milliseconds.
+    pub recv_window: Option<i64>,
+    /// The request time in milliseconds, which is set by the client on signing.
+    pub timestamp: i64,
+}
+
+impl Default for Query {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Query {
+    /// The query params default capacity.
+    const QUERY_INITIAL_CAPACITY: usize = 64;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new() -> Self {
+        Self {
+            recv_window: None,
+            timestamp: 0,
+        }
+    }
+
+    ///
+    /// The request weight.
+    ///
+    pub fn weight(&self) -> u32 {
+        6
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut params =
+            form_urlencoded::Serializer::new(String::with_capacity(Self::QUERY_INITIAL_CAPACITY));
+        if let Some(recv_window) = self.recv_window {
+            params.append_pair("recvWindow", &recv_window.to_string());
+        }
+        params.append_pair("timestamp", &self.timestamp.to_string());
+        write!(f, "{}", params.finish())
+    }
+}
+
+impl SignedQuery for Query {
+    fn set_timestamp(&mut self, timestamp: i64) {
+        self.timestamp = timestamp;
+    }
+}
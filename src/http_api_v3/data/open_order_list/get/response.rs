@@ -0,0 +1,10 @@
+//!
+//! The open order list GET response.
+//!
+
+use crate::http_api_v3::data::order_list::OrderList;
+
+///
+/// The `https://www.binance.com/api/v3/openOrderList` GET response.
+///
+pub type Response = Vec<OrderList>;
@@ -0,0 +1,5 @@
+//!
+//! The open order list.
+//!
+
+pub mod get;
@@ -2,49 +2,45 @@
 //! The open orders DELETE response.
 //!
 
-use rust_decimal::Decimal;
 use serde::Deserialize;
 
-use crate::http_api_v3::data::order_side::OrderSide;
-use crate::http_api_v3::data::order_status::OrderStatus;
-use crate::http_api_v3::data::order_time_in_force::OrderTimeInForce;
-use crate::http_api_v3::data::order_type::OrderType;
+use crate::http_api_v3::data::error::Error as ResponseError;
+use crate::http_api_v3::data::order::delete::response::Response as OrderDeleteResponse;
 
 ///
-/// The `https://www.binance.com/api/v3/openOrders` GET response.
+/// The `https://www.binance.com/api/v3/openOrders` DELETE response.
 ///
-pub type Response = Vec<OpenOrder>;
+pub type Response = Vec<Report>;
 
 ///
-/// A single open order element.
+/// A single order cancellation report.
 ///
 #[derive(Debug, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct OpenOrder {
-    /// The symbol name.
-    pub symbol: String,
-    /// The original client-side order ID.
-    pub orig_client_order_id: String,
-    /// The server-side order ID.
-    pub order_id: i64,
-    /// The server-side order list ID.
-    pub order_list_id: i64,
-    /// The client-side order ID.
-    pub client_order_id: String,
-    /// The order price.
-    pub price: Decimal,
-    /// The initial order quantity.
-    pub orig_qty: Decimal,
-    /// The order quantity executed so far.
-    pub executed_qty: Decimal,
-    /// Usually the same as `executed_qty`.
-    pub cummulative_quote_qty: Decimal,
-    /// The order status.
-    pub status: OrderStatus,
-    /// The order time-in-force.
-    pub time_in_force: OrderTimeInForce,
-    /// The order type.
-    pub r#type: OrderType,
-    /// The order side.
-    pub side: OrderSide,
+#[serde(untagged)]
+pub enum Report {
+    /// The order has been cancelled.
+    Canceled(Box<OrderDeleteResponse>),
+    /// The order has not been cancelled, e.g. because it was filled in the meantime.
+    Failed(ResponseError),
+    /// Fallback for all other reports, e.g. the cancelled order lists.
+    Other(serde_json::Value),
+}
+
+impl Report {
+    ///
+    /// Returns the cancelled order data, if the order has been cancelled.
+    ///
+    pub fn canceled(&self) -> Option<&OrderDeleteResponse> {
+        match self {
+            Report::Canceled(inner) => Some(inner.as_ref()),
+            _ => None,
+        }
+    }
+
+    ///
+    /// If the order has been cancelled.
+    ///
+    pub fn is_canceled(&self) -> bool {
+        matches!(self, Report::Canceled(_))
+    }
 }
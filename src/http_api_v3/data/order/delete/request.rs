@@ -2,11 +2,12 @@
 //! The order DELETE request.
 //!
 
-use chrono::prelude::*;
+use crate::http_api_v3::signed_query::SignedQuery;
 
 ///
 /// The `https://www.binance.com/api/v3/order` POST request query.
 ///
+#[derive(Debug)]
 pub struct Query {
     /// The symbol name.
     pub symbol: String,
@@ -18,7 +19,7 @@ pub struct Query {
     pub new_client_order_id: Option<String>,
     /// The allowed time window between the request and response in milliseconds.
     pub recv_window: Option<i64>,
-    /// The request time in milliseconds.
+    /// The request time in milliseconds, which is set by the client on signing.
     pub timestamp: i64,
 }
 
@@ -36,28 +37,42 @@ impl Query {
             orig_client_order_id: Some(orig_client_order_id.to_owned()),
             new_client_order_id: None,
             recv_window: None,
-            timestamp: Utc::now().timestamp_millis(),
+            timestamp: 0,
         }
     }
+
+    ///
+    /// The request weight.
+    ///
+    pub fn weight(&self) -> u32 {
+        1
+    }
 }
 
 impl ToString for Query {
     fn to_string(&self) -> String {
-        let mut params = String::with_capacity(Self::QUERY_INITIAL_CAPACITY);
-        params += &format!("symbol={}", self.symbol.to_owned());
+        let mut params =
+            form_urlencoded::Serializer::new(String::with_capacity(Self::QUERY_INITIAL_CAPACITY));
+        params.append_pair("symbol", self.symbol.as_str());
         if let Some(order_id) = self.order_id {
-            params += &format!("&orderId={}", order_id.to_string());
+            params.append_pair("orderId", &order_id.to_string());
         }
         if let Some(ref orig_client_order_id) = self.orig_client_order_id {
-            params += &format!("&origClientOrderId={}", orig_client_order_id.to_owned());
+            params.append_pair("origClientOrderId", orig_client_order_id.as_str());
         }
         if let Some(ref new_client_order_id) = self.new_client_order_id {
-            params += &format!("&newClientOrderId={}", new_client_order_id.to_owned());
+            params.append_pair("newClientOrderId", new_client_order_id.as_str());
         }
         if let Some(recv_window) = self.recv_window {
-            params += &format!("&recvWindow={}", recv_window.to_string());
+            params.append_pair("recvWindow", &recv_window.to_string());
         }
-        params += &format!("&timestamp={}", self.timestamp.to_string());
-        params
+        params.append_pair("timestamp", &self.timestamp.to_string());
+        params.finish()
+    }
+}
+
+impl SignedQuery for Query {
+    fn set_timestamp(&mut self, timestamp: i64) {
+        self.timestamp = timestamp;
     }
 }
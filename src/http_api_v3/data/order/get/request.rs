@@ -2,11 +2,12 @@
 //! The order GET request.
 //!
 
-use chrono::prelude::*;
+use crate::http_api_v3::signed_query::SignedQuery;
 
 ///
 /// The `https://www.binance.com/api/v3/order` GET request query.
 ///
+#[derive(Debug)]
 pub struct Query {
     /// The symbol name.
     pub symbol: String,
@@ -16,7 +17,7 @@ pub struct Query {
     pub orig_client_order_id: Option<String>,
     /// The allowed time window between the request and response in milliseconds.
     pub recv_window: Option<i64>,
-    /// The request time in milliseconds.
+    /// The request time in milliseconds, which is set by the client on signing.
     pub timestamp: i64,
 }
 
@@ -33,25 +34,64 @@ impl Query {
             order_id: None,
             orig_client_order_id: Some(orig_client_order_id.to_owned()),
             recv_window: None,
-            timestamp: Utc::now().timestamp_millis(),
+            timestamp: 0,
         }
     }
+
+    ///
+    /// The request weight.
+    ///
+    pub fn weight(&self) -> u32 {
+        4
+    }
 }
 
 impl ToString for Query {
     fn to_string(&self) -> String {
-        let mut params = String::with_capacity(Self::QUERY_INITIAL_CAPACITY);
-        params += &format!("symbol={}", self.symbol.to_owned());
+        let mut params =
+            form_urlencoded::Serializer::new(String::with_capacity(Self::QUERY_INITIAL_CAPACITY));
+        params.append_pair("symbol", self.symbol.as_str());
         if let Some(order_id) = self.order_id {
-            params += &format!("&orderId={}", order_id.to_string());
+            params.append_pair("orderId", &order_id.to_string());
         }
         if let Some(ref orig_client_order_id) = self.orig_client_order_id {
-            params += &format!("&origClientOrderId={}", orig_client_order_id.to_owned());
+            params.append_pair("origClientOrderId", orig_client_order_id.as_str());
         }
         if let Some(recv_window) = self.recv_window {
-            params += &format!("&recvWindow={}", recv_window.to_string());
+            params.append_pair("recvWindow", &recv_window.to_string());
         }
-        params += &format!("&timestamp={}", self.timestamp.to_string());
-        params
+        params.append_pair("timestamp", &self.timestamp.to_string());
+        params.finish()
+    }
+}
+
+impl SignedQuery for Query {
+    fn set_timestamp(&mut self, timestamp: i64) {
+        self.timestamp = timestamp;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::http_api_v3::Client;
+
+    use super::Query;
+
+    #[test]
+    fn empty_query_fails_before_signing() {
+        let query = Query::new("", "");
+
+        match Client::validate_signed_params(query.to_string().as_str()) {
+            Err(Error::InvalidSignedRequest(_)) => {}
+            result => panic!("expected InvalidSignedRequest, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn filled_query_passes() {
+        let query = Query::new("BTCUSDT", "order");
+
+        assert!(Client::validate_signed_params(query.to_string().as_str()).is_ok());
     }
 }
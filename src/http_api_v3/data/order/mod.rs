@@ -5,3 +5,89 @@
 pub mod delete;
 pub mod get;
 pub mod post;
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::http_api_v3::data::order_side::OrderSide;
+use crate::http_api_v3::data::order_status::OrderStatus;
+use crate::http_api_v3::data::order_time_in_force::OrderTimeInForce;
+use crate::http_api_v3::data::order_type::OrderType;
+use crate::http_api_v3::data::self_trade_prevention_mode::SelfTradePreventionMode;
+use crate::http_api_v3::data::serde::deserialize_optional_decimal;
+
+///
+/// The order data, which is shared by the order, open orders, and all orders GET responses.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    /// The symbol name.
+    pub symbol: String,
+    /// The server-side order ID.
+    pub order_id: i64,
+    /// The server-side order list ID. `-1` if the order is not a part of a list.
+    pub order_list_id: i64,
+    /// The client-side order ID.
+    pub client_order_id: String,
+    /// The order price.
+    pub price: Decimal,
+    /// The initial order quantity.
+    pub orig_qty: Decimal,
+    /// The order quantity executed so far.
+    pub executed_qty: Decimal,
+    /// Usually the same as `executed_qty`.
+    pub cummulative_quote_qty: Decimal,
+    /// The order status.
+    pub status: OrderStatus,
+    /// The order time-in-force.
+    pub time_in_force: OrderTimeInForce,
+    /// The order type.
+    pub r#type: OrderType,
+    /// The order side.
+    pub side: OrderSide,
+    /// Used with `STOP_LOSS`, `STOP_LOSS_LIMIT`, `TAKE_PROFIT`, and `TAKE_PROFIT_LIMIT` orders.
+    #[serde(default, deserialize_with = "deserialize_optional_decimal")]
+    pub stop_price: Option<Decimal>,
+    /// The iceberg order quantity.
+    #[serde(default, deserialize_with = "deserialize_optional_decimal")]
+    pub iceberg_qty: Option<Decimal>,
+    /// The order time in milliseconds.
+    pub time: i64,
+    /// Usually the same as `time`.
+    pub update_time: i64,
+    /// Unknown value.
+    pub is_working: bool,
+    /// Usually the same as `orig_qty`.
+    pub orig_quote_order_qty: Decimal,
+    /// The self-trade prevention mode.
+    #[serde(default)]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    /// The prevented match ID, if the order has been expired by the self-trade prevention.
+    #[serde(default)]
+    pub prevented_match_id: Option<i64>,
+    /// The order quantity, which has been prevented by the self-trade prevention.
+    #[serde(default, deserialize_with = "deserialize_optional_decimal")]
+    pub prevented_quantity: Option<Decimal>,
+}
+
+impl Order {
+    ///
+    /// The order quantity, which is left to be executed.
+    ///
+    pub fn remaining_qty(&self) -> Decimal {
+        self.orig_qty - self.executed_qty
+    }
+
+    ///
+    /// The executed part of the order quantity from 0 to 1.
+    ///
+    /// Zero if the initial quantity is zero.
+    ///
+    pub fn fill_ratio(&self) -> Decimal {
+        if self.orig_qty == Decimal::new(0, 0) {
+            return Decimal::new(0, 0);
+        }
+        self.executed_qty / self.orig_qty
+    }
+}
@@ -2,17 +2,20 @@
 //! The order POST request.
 //!
 
-use chrono::prelude::*;
 use rust_decimal::Decimal;
 
+use crate::error::Error;
 use crate::http_api_v3::data::order::post::response::r#type::Type as ResponseType;
 use crate::http_api_v3::data::order_side::OrderSide;
 use crate::http_api_v3::data::order_time_in_force::OrderTimeInForce;
 use crate::http_api_v3::data::order_type::OrderType;
+use crate::http_api_v3::data::self_trade_prevention_mode::SelfTradePreventionMode;
+use crate::http_api_v3::signed_query::SignedQuery;
 
 ///
 /// The `https://www.binance.com/api/v3/order` POST request query.
 ///
+#[derive(Debug, Clone)]
 pub struct Query {
     /// The symbol name.
     pub symbol: String,
@@ -34,12 +37,17 @@ pub struct Query {
     pub stop_price: Option<Decimal>,
     /// The iceberg order quantity.
     pub iceberg_qty: Option<Decimal>,
+    /// The trailing stop delta in basis points. Used with `STOP_LOSS`, `STOP_LOSS_LIMIT`,
+    /// `TAKE_PROFIT`, and `TAKE_PROFIT_LIMIT` orders.
+    pub trailing_delta: Option<u32>,
     /// Set the response JSON. ACK, RESULT, or FULL;
     /// MARKET and LIMIT order types default to FULL, all other orders default to ACK.
     pub new_order_resp_type: Option<ResponseType>,
+    /// The self-trade prevention mode. Defaults to the symbol default mode.
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
     /// The allowed time window between the request and response in milliseconds.
     pub recv_window: Option<i64>,
-    /// The request time in milliseconds.
+    /// The request time in milliseconds, which is set by the client on signing.
     pub timestamp: i64,
 }
 
@@ -73,9 +81,11 @@ impl Query {
             new_client_order_id: None,
             stop_price: None,
             iceberg_qty: None,
+            trailing_delta: None,
             new_order_resp_type: Some(ResponseType::Full),
+            self_trade_prevention_mode: None,
             recv_window: None,
-            timestamp: Utc::now().timestamp_millis(),
+            timestamp: 0,
         }
     }
 
@@ -94,47 +104,122 @@ impl Query {
             new_client_order_id: None,
             stop_price: None,
             iceberg_qty: None,
+            trailing_delta: None,
             new_order_resp_type: Some(ResponseType::Ack),
+            self_trade_prevention_mode: None,
             recv_window: None,
-            timestamp: Utc::now().timestamp_millis(),
+            timestamp: 0,
         }
     }
+
+    ///
+    /// Creates a trailing stop-loss market order request, which is triggered after the price
+    /// moves against the best price by `trailing_delta` basis points.
+    ///
+    pub fn trailing_stop_loss(
+        symbol: &str,
+        side: OrderSide,
+        quantity: Decimal,
+        trailing_delta: u32,
+    ) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            side,
+            r#type: OrderType::StopLoss,
+            time_in_force: None,
+            quantity: Some(quantity),
+            quote_order_qty: None,
+            price: None,
+            new_client_order_id: None,
+            stop_price: None,
+            iceberg_qty: None,
+            trailing_delta: Some(trailing_delta),
+            new_order_resp_type: Some(ResponseType::Ack),
+            self_trade_prevention_mode: None,
+            recv_window: None,
+            timestamp: 0,
+        }
+    }
+
+    ///
+    /// Checks that the trailing delta is only set on the stop-loss and take-profit orders,
+    /// since Binance rejects it on the other order types.
+    ///
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.trailing_delta.is_none() {
+            return Ok(());
+        }
+
+        match &self.r#type {
+            OrderType::StopLoss
+            | OrderType::StopLossLimit
+            | OrderType::TakeProfit
+            | OrderType::TakeProfitLimit => Ok(()),
+            r#type => Err(Error::InvalidRequest(format!(
+                "trailing delta is not supported by the {} order type",
+                r#type
+            ))),
+        }
+    }
+
+    ///
+    /// The request weight.
+    ///
+    pub fn weight(&self) -> u32 {
+        1
+    }
 }
 
 impl ToString for Query {
     fn to_string(&self) -> String {
-        let mut params = String::with_capacity(Self::QUERY_INITIAL_CAPACITY);
-        params += &format!("symbol={}", self.symbol.to_owned());
-        params += &format!("&side={}", self.side.to_string());
-        params += &format!("&type={}", self.r#type.to_string());
-        if let Some(time_in_force) = self.time_in_force {
-            params += &format!("&timeInForce={}", time_in_force.to_string());
+        let mut params =
+            form_urlencoded::Serializer::new(String::with_capacity(Self::QUERY_INITIAL_CAPACITY));
+        params.append_pair("symbol", self.symbol.as_str());
+        params.append_pair("side", &self.side.to_string());
+        params.append_pair("type", &self.r#type.to_string());
+        if let Some(time_in_force) = self.time_in_force.as_ref() {
+            params.append_pair("timeInForce", &time_in_force.to_string());
         }
         if let Some(quantity) = self.quantity {
-            params += &format!("&quantity={}", quantity.to_string());
+            params.append_pair("quantity", &quantity.to_string());
         }
         if let Some(quote_order_qty) = self.quote_order_qty {
-            params += &format!("&quoteOrderQty={}", quote_order_qty.to_string());
+            params.append_pair("quoteOrderQty", &quote_order_qty.to_string());
         }
         if let Some(price) = self.price {
-            params += &format!("&price={}", price.to_string());
+            params.append_pair("price", &price.to_string());
         }
         if let Some(ref new_client_order_id) = self.new_client_order_id {
-            params += &format!("&newClientOrderId={}", new_client_order_id.to_owned());
+            params.append_pair("newClientOrderId", new_client_order_id.as_str());
         }
         if let Some(stop_price) = self.stop_price {
-            params += &format!("&stopPrice={}", stop_price.to_string());
+            params.append_pair("stopPrice", &stop_price.to_string());
         }
         if let Some(iceberg_qty) = self.iceberg_qty {
-            params += &format!("&icebergQty={}", iceberg_qty.to_string());
+            params.append_pair("icebergQty", &iceberg_qty.to_string());
+        }
+        if let Some(trailing_delta) = self.trailing_delta {
+            params.append_pair("trailingDelta", &trailing_delta.to_string());
         }
         if let Some(new_order_resp_type) = self.new_order_resp_type {
-            params += &format!("&newOrderRespType={}", new_order_resp_type.to_string());
+            params.append_pair("newOrderRespType", &new_order_resp_type.to_string());
+        }
+        if let Some(self_trade_prevention_mode) = self.self_trade_prevention_mode {
+            params.append_pair(
+                "selfTradePreventionMode",
+                &self_trade_prevention_mode.to_string(),
+            );
         }
         if let Some(recv_window) = self.recv_window {
-            params += &format!("&recvWindow={}", recv_window.to_string());
+            params.append_pair("recvWindow", &recv_window.to_string());
         }
-        params += &format!("&timestamp={}", self.timestamp.to_string());
-        params
+        params.append_pair("timestamp", &self.timestamp.to_string());
+        params.finish()
+    }
+}
+
+impl SignedQuery for Query {
+    fn set_timestamp(&mut self, timestamp: i64) {
+        self.timestamp = timestamp;
     }
 }
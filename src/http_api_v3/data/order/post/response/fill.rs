@@ -8,6 +8,8 @@ use serde::Deserialize;
 ///
 /// The order partial fill.
 ///
+/// The fills returned on placement are always the taker trades.
+///
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Fill {
@@ -19,4 +21,13 @@ pub struct Fill {
     pub commission: Decimal,
     /// The token of the commission of the trade event.
     pub commission_asset: String,
+    /// The trade ID. `-1` for the SOR allocations.
+    #[serde(default)]
+    pub trade_id: Option<i64>,
+    /// The SOR fill type, e.g. `ONE_PARTY_TRADE_REPORT`. Only set for the SOR orders.
+    #[serde(default)]
+    pub match_type: Option<String>,
+    /// The SOR allocation ID. Only set for the SOR orders.
+    #[serde(default)]
+    pub alloc_id: Option<i64>,
 }
@@ -10,6 +10,8 @@ use crate::http_api_v3::data::order_side::OrderSide;
 use crate::http_api_v3::data::order_status::OrderStatus;
 use crate::http_api_v3::data::order_time_in_force::OrderTimeInForce;
 use crate::http_api_v3::data::order_type::OrderType;
+use crate::http_api_v3::data::self_trade_prevention_mode::SelfTradePreventionMode;
+use crate::http_api_v3::data::serde::deserialize_optional_decimal;
 
 ///
 /// The `https://www.binance.com/api/v3/order` POST full-type response.
@@ -43,6 +45,45 @@ pub struct Full {
     pub r#type: OrderType,
     /// The order side.
     pub side: OrderSide,
-    /// The order partial fills.
+    /// The order partial fills. Spread across several order books for the SOR orders.
     pub fills: Vec<Fill>,
+    /// The order book the order rests on, e.g. `SOR`. Only set for the SOR orders.
+    #[serde(default)]
+    pub working_floor: Option<String>,
+    /// If the order has been placed with the Smart Order Routing.
+    #[serde(default)]
+    pub used_sor: bool,
+    /// The self-trade prevention mode.
+    #[serde(default)]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    /// The prevented match ID, if the order has been expired by the self-trade prevention.
+    #[serde(default)]
+    pub prevented_match_id: Option<i64>,
+    /// The order quantity, which has been prevented by the self-trade prevention.
+    #[serde(default, deserialize_with = "deserialize_optional_decimal")]
+    pub prevented_quantity: Option<Decimal>,
+}
+
+impl Full {
+    ///
+    /// If the order has taken liquidity on placement, that is, it has been filled immediately at
+    /// least partially, and the taker commission has been charged for the `fills`.
+    ///
+    pub fn is_taker(&self) -> bool {
+        !self.fills.is_empty()
+    }
+
+    ///
+    /// If the order rests in the order book, so the maker commission applies to its later fills.
+    ///
+    pub fn is_maker(&self) -> bool {
+        self.status.is_fillable() && self.executed_qty < self.orig_qty
+    }
+
+    ///
+    /// The quantity filled immediately on placement as the taker.
+    ///
+    pub fn taker_qty(&self) -> Decimal {
+        self.fills.iter().map(|fill| fill.qty).sum()
+    }
 }
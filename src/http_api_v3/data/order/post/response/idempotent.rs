@@ -0,0 +1,29 @@
+//!
+//! The idempotent order POST response.
+//!
+
+use crate::http_api_v3::data::order::get::response::Response as OrderGetResponse;
+use crate::http_api_v3::data::order::post::response::Response as OrderPostResponse;
+
+///
+/// The idempotent order POST response.
+///
+#[derive(Debug, Clone)]
+pub enum Idempotent {
+    /// The order has been placed by the successful request.
+    Placed(OrderPostResponse),
+    /// The order has been placed by the failed request, which reached Binance anyway.
+    Existing(OrderGetResponse),
+}
+
+impl Idempotent {
+    ///
+    /// Returns the client order ID from the inner types.
+    ///
+    pub fn client_order_id(&self) -> String {
+        match self {
+            Self::Placed(inner) => inner.client_order_id(),
+            Self::Existing(inner) => inner.client_order_id.to_owned(),
+        }
+    }
+}
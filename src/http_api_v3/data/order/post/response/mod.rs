@@ -5,6 +5,7 @@
 pub mod ack;
 pub mod fill;
 pub mod full;
+pub mod idempotent;
 pub mod result;
 pub mod r#type;
 
@@ -9,6 +9,8 @@ use crate::http_api_v3::data::order_side::OrderSide;
 use crate::http_api_v3::data::order_status::OrderStatus;
 use crate::http_api_v3::data::order_time_in_force::OrderTimeInForce;
 use crate::http_api_v3::data::order_type::OrderType;
+use crate::http_api_v3::data::self_trade_prevention_mode::SelfTradePreventionMode;
+use crate::http_api_v3::data::serde::deserialize_optional_decimal;
 
 ///
 /// The `https://www.binance.com/api/v3/order` POST result-type response.
@@ -42,4 +44,30 @@ pub struct Result {
     pub r#type: OrderType,
     /// The order side.
     pub side: OrderSide,
+    /// The self-trade prevention mode.
+    #[serde(default)]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    /// The prevented match ID, if the order has been expired by the self-trade prevention.
+    #[serde(default)]
+    pub prevented_match_id: Option<i64>,
+    /// The order quantity, which has been prevented by the self-trade prevention.
+    #[serde(default, deserialize_with = "deserialize_optional_decimal")]
+    pub prevented_quantity: Option<Decimal>,
+}
+
+impl Result {
+    ///
+    /// If the order has taken liquidity on placement, that is, it has been filled immediately at
+    /// least partially, and the taker commission has been charged for `executed_qty`.
+    ///
+    pub fn is_taker(&self) -> bool {
+        self.executed_qty > Decimal::new(0, 0)
+    }
+
+    ///
+    /// If the order rests in the order book, so the maker commission applies to its later fills.
+    ///
+    pub fn is_maker(&self) -> bool {
+        self.status.is_fillable() && self.executed_qty < self.orig_qty
+    }
 }
@@ -0,0 +1,5 @@
+//!
+//! The order amend keep priority.
+//!
+
+pub mod put;
@@ -0,0 +1,6 @@
+//!
+//! The order amend keep priority PUT.
+//!
+
+pub mod request;
+pub mod response;
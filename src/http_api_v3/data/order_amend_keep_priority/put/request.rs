@@ -0,0 +1,86 @@
+//!
+//! The order amend keep priority PUT request.
+//!
+
+use std::fmt;
+
+use rust_decimal::Decimal;
+
+use crate::http_api_v3::signed_query::SignedQuery;
+
+///
+/// The `https://www.binance.com/api/v3/order/amend/keepPriority` PUT request query.
+///
+#[derive(Debug)]
+pub struct Query {
+    /// The symbol name.
+    pub symbol: String,
+    /// The order ID to amend.
+    pub order_id: Option<i64>,
+    /// Either `orderId` or `origClientOrderId` must be sent.
+    pub orig_client_order_id: Option<String>,
+    /// The new client-side order ID. Automatically generated by default.
+    pub new_client_order_id: Option<String>,
+    /// The new order quantity, which must be less than the current one.
+    pub new_qty: Decimal,
+    /// The allowed time window between the request and response in milliseconds.
+    pub recv_window: Option<i64>,
+    /// The request time in milliseconds, which is set by the client on signing.
+    pub timestamp: i64,
+}
+
+impl Query {
+    /// The query params default capacity.
+    const QUERY_INITIAL_CAPACITY: usize = 256;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(symbol: &str, orig_client_order_id: &str, new_qty: Decimal) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            order_id: None,
+            orig_client_order_id: Some(orig_client_order_id.to_owned()),
+            new_client_order_id: None,
+            new_qty,
+            recv_window: None,
+            timestamp: 0,
+        }
+    }
+
+    ///
+    /// The request weight.
+    ///
+    pub fn weight(&self) -> u32 {
+        4
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut params =
+            form_urlencoded::Serializer::new(String::with_capacity(Self::QUERY_INITIAL_CAPACITY));
+        params.append_pair("symbol", self.symbol.as_str());
+        if let Some(order_id) = self.order_id {
+            params.append_pair("orderId", &order_id.to_string());
+        }
+        if let Some(ref orig_client_order_id) = self.orig_client_order_id {
+            params.append_pair("origClientOrderId", orig_client_order_id.as_str());
+        }
+        if let Some(ref new_client_order_id) = self.new_client_order_id {
+            params.append_pair("newClientOrderId", new_client_order_id.as_str());
+        }
+        params.append_pair("newQty", &self.new_qty.to_string());
+        if let Some(recv_window) = self.recv_window {
+            params.append_pair("recvWindow", &recv_window.to_string());
+        }
+        params.append_pair("timestamp", &self.timestamp.to_string());
+        write!(f, "{}", params.finish())
+    }
+}
+
+impl SignedQuery for Query {
+    fn set_timestamp(&mut self, timestamp: i64) {
+        self.timestamp = timestamp;
+    }
+}
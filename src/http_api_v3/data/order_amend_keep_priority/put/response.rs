@@ -0,0 +1,65 @@
+//!
+//! The order amend keep priority PUT response.
+//!
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::http_api_v3::data::order_side::OrderSide;
+use crate::http_api_v3::data::order_status::OrderStatus;
+use crate::http_api_v3::data::order_time_in_force::OrderTimeInForce;
+use crate::http_api_v3::data::order_type::OrderType;
+
+///
+/// The `https://www.binance.com/api/v3/order/amend/keepPriority` PUT response.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    /// The time when the order was amended.
+    pub transact_time: i64,
+    /// The amendment execution ID.
+    pub execution_id: i64,
+    /// The order data after the amendment.
+    pub amended_order: AmendedOrder,
+}
+
+///
+/// The order data after the amendment.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendedOrder {
+    /// The symbol name.
+    pub symbol: String,
+    /// The server-side order ID, which is kept by the amendment.
+    pub order_id: i64,
+    /// The server-side order list ID.
+    pub order_list_id: i64,
+    /// The client-side order ID before the amendment.
+    pub orig_client_order_id: String,
+    /// The client-side order ID after the amendment.
+    pub client_order_id: String,
+    /// The order price.
+    pub price: Decimal,
+    /// The amended order quantity.
+    pub qty: Decimal,
+    /// The order quantity executed so far.
+    pub executed_qty: Decimal,
+    /// The order quantity expired due to the self-trade prevention.
+    pub prevented_qty: Decimal,
+    /// The order quantity in the primary asset.
+    pub quote_order_qty: Decimal,
+    /// The executed quantity in the primary asset.
+    pub cumulative_quote_qty: Decimal,
+    /// The order status.
+    pub status: OrderStatus,
+    /// The order time-in-force.
+    pub time_in_force: OrderTimeInForce,
+    /// The order type.
+    pub r#type: OrderType,
+    /// The order side.
+    pub side: OrderSide,
+    /// The time when the order started working in the order book.
+    pub working_time: i64,
+}
@@ -0,0 +1,72 @@
+//!
+//! The order list contingency type.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de;
+use serde::Deserialize;
+use serde::Deserializer;
+
+///
+/// The order list contingency type.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ContingencyType {
+    /// The one-cancels-the-other order list.
+    Oco,
+    /// The one-triggers-the-other order list.
+    Oto,
+    /// The value unknown to this version of the library, which is kept as is.
+    Unknown(String),
+}
+
+impl fmt::Display for ContingencyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Oco => "OCO",
+                Self::Oto => "OTO",
+                Self::Unknown(value) => value.as_str(),
+            }
+        )
+    }
+}
+
+impl FromStr for ContingencyType {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "OCO" => Self::Oco,
+            "OTO" => Self::Oto,
+            "" => return Err(value.to_owned()),
+            value => Self::Unknown(value.to_owned()),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for ContingencyType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ContingencyType;
+
+    string_enum_tests!(
+        ContingencyType,
+        "OTOCO",
+        [(ContingencyType::Oco, "OCO"), (ContingencyType::Oto, "OTO")]
+    );
+}
@@ -0,0 +1,6 @@
+//!
+//! The order list DELETE.
+//!
+
+pub mod request;
+pub mod response;
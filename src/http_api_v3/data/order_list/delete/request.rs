@@ -0,0 +1,94 @@
+//!
+//! The order list DELETE request.
+//!
+
+use std::fmt;
+
+use crate::http_api_v3::signed_query::SignedQuery;
+
+///
+/// The `https://www.binance.com/api/v3/orderList` DELETE request query.
+///
+#[derive(Debug)]
+pub struct Query {
+    /// The symbol name.
+    pub symbol: String,
+    /// The server-side order list ID to cancel.
+    pub order_list_id: Option<i64>,
+    /// Either `orderListId` or `listClientOrderId` must be sent.
+    pub list_client_order_id: Option<String>,
+    /// Used to uniquely identify this cancel. Automatically generated by default.
+    pub new_client_order_id: Option<String>,
+    /// The allowed time window between the request and response in milliseconds.
+    pub recv_window: Option<i64>,
+    /// The request time in milliseconds, which is set by the client on signing.
+    pub timestamp: i64,
+}
+
+impl Query {
+    /// The query params default capacity.
+    const QUERY_INITIAL_CAPACITY: usize = 256;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(symbol: &str, order_list_id: i64) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            order_list_id: Some(order_list_id),
+            list_client_order_id: None,
+            new_client_order_id: None,
+            recv_window: None,
+            timestamp: 0,
+        }
+    }
+
+    ///
+    /// A shortcut constructor, which finds the order list by its client-side ID.
+    ///
+    pub fn new_with_client_id(symbol: &str, list_client_order_id: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            order_list_id: None,
+            list_client_order_id: Some(list_client_order_id.to_owned()),
+            new_client_order_id: None,
+            recv_window: None,
+            timestamp: 0,
+        }
+    }
+
+    ///
+    /// The request weight.
+    ///
+    pub fn weight(&self) -> u32 {
+        1
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut params =
+            form_urlencoded::Serializer::new(String::with_capacity(Self::QUERY_INITIAL_CAPACITY));
+        params.append_pair("symbol", self.symbol.as_str());
+        if let Some(order_list_id) = self.order_list_id {
+            params.append_pair("orderListId", &order_list_id.to_string());
+        }
+        if let Some(ref list_client_order_id) = self.list_client_order_id {
+            params.append_pair("listClientOrderId", list_client_order_id.as_str());
+        }
+        if let Some(ref new_client_order_id) = self.new_client_order_id {
+            params.append_pair("newClientOrderId", new_client_order_id.as_str());
+        }
+        if let Some(recv_window) = self.recv_window {
+            params.append_pair("recvWindow", &recv_window.to_string());
+        }
+        params.append_pair("timestamp", &self.timestamp.to_string());
+        write!(f, "{}", params.finish())
+    }
+}
+
+impl SignedQuery for Query {
+    fn set_timestamp(&mut self, timestamp: i64) {
+        self.timestamp = timestamp;
+    }
+}
@@ -0,0 +1,11 @@
+//!
+//! The order list DELETE response.
+//!
+
+use crate::http_api_v3::data::order_list::OrderList;
+
+///
+/// The `https://www.binance.com/api/v3/orderList` DELETE response, which contains the cancelled
+/// order reports.
+///
+pub type Response = OrderList;
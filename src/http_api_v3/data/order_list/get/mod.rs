@@ -0,0 +1,6 @@
+//!
+//! The order list GET.
+//!
+
+pub mod request;
+pub mod response;
@@ -0,0 +1,82 @@
+//!
+//! The order list GET request.
+//!
+
+use std::fmt;
+
+use crate::http_api_v3::signed_query::SignedQuery;
+
+///
+/// The `https://www.binance.com/api/v3/orderList` GET request query.
+///
+#[derive(Debug)]
+pub struct Query {
+    /// The server-side order list ID.
+    pub order_list_id: Option<i64>,
+    /// Either `orderListId` or `origClientOrderId` must be sent.
+    pub orig_client_order_id: Option<String>,
+    /// The allowed time window between the request and response in milliseconds.
+    pub recv_window: Option<i64>,
+    /// The request time in milliseconds, which is set by the client on signing.
+    pub timestamp: i64,
+}
+
+impl Query {
+    /// The query params default capacity.
+    const QUERY_INITIAL_CAPACITY: usize = 256;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(order_list_id: i64) -> Self {
+        Self {
+            order_list_id: Some(order_list_id),
+            orig_client_order_id: None,
+            recv_window: None,
+            timestamp: 0,
+        }
+    }
+
+    ///
+    /// A shortcut constructor, which finds the order list by its client-side ID.
+    ///
+    pub fn new_with_client_id(orig_client_order_id: &str) -> Self {
+        Self {
+            order_list_id: None,
+            orig_client_order_id: Some(orig_client_order_id.to_owned()),
+            recv_window: None,
+            timestamp: 0,
+        }
+    }
+
+    ///
+    /// The request weight.
+    ///
+    pub fn weight(&self) -> u32 {
+        4
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut params =
+            form_urlencoded::Serializer::new(String::with_capacity(Self::QUERY_INITIAL_CAPACITY));
+        if let Some(order_list_id) = self.order_list_id {
+            params.append_pair("orderListId", &order_list_id.to_string());
+        }
+        if let Some(ref orig_client_order_id) = self.orig_client_order_id {
+            params.append_pair("origClientOrderId", orig_client_order_id.as_str());
+        }
+        if let Some(recv_window) = self.recv_window {
+            params.append_pair("recvWindow", &recv_window.to_string());
+        }
+        params.append_pair("timestamp", &self.timestamp.to_string());
+        write!(f, "{}", params.finish())
+    }
+}
+
+impl SignedQuery for Query {
+    fn set_timestamp(&mut self, timestamp: i64) {
+        self.timestamp = timestamp;
+    }
+}
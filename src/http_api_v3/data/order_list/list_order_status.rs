@@ -0,0 +1,89 @@
+//!
+//! The order list execution status.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de;
+use serde::Deserialize;
+use serde::Deserializer;
+
+///
+/// The order list execution status.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ListOrderStatus {
+    /// The order list is placed or being executed.
+    Executing,
+    /// The order list execution has finished.
+    AllDone,
+    /// The order list is rejected.
+    Reject,
+    /// The value unknown to this version of the library, which is kept as is.
+    Unknown(String),
+}
+
+impl ListOrderStatus {
+    ///
+    /// If the order list has reached its final state, that is, `ALL_DONE` or `REJECT`.
+    ///
+    pub fn is_done(&self) -> bool {
+        matches!(self, ListOrderStatus::AllDone | ListOrderStatus::Reject)
+    }
+}
+
+impl fmt::Display for ListOrderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Executing => "EXECUTING",
+                Self::AllDone => "ALL_DONE",
+                Self::Reject => "REJECT",
+                Self::Unknown(value) => value.as_str(),
+            }
+        )
+    }
+}
+
+impl FromStr for ListOrderStatus {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "EXECUTING" => Self::Executing,
+            "ALL_DONE" => Self::AllDone,
+            "REJECT" => Self::Reject,
+            "" => return Err(value.to_owned()),
+            value => Self::Unknown(value.to_owned()),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for ListOrderStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ListOrderStatus;
+
+    string_enum_tests!(
+        ListOrderStatus,
+        "PAUSED",
+        [
+            (ListOrderStatus::Executing, "EXECUTING"),
+            (ListOrderStatus::AllDone, "ALL_DONE"),
+            (ListOrderStatus::Reject, "REJECT"),
+        ]
+    );
+}
@@ -0,0 +1,89 @@
+//!
+//! The order list status.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de;
+use serde::Deserialize;
+use serde::Deserializer;
+
+///
+/// The order list status.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ListStatusType {
+    /// The order list is placed or updated, but not executed yet.
+    Response,
+    /// The order list is being executed.
+    ExecStarted,
+    /// The order list execution has finished, so it is no longer active.
+    AllDone,
+    /// The value unknown to this version of the library, which is kept as is.
+    Unknown(String),
+}
+
+impl ListStatusType {
+    ///
+    /// If the order list is no longer active, that is, `ALL_DONE`.
+    ///
+    pub fn is_done(&self) -> bool {
+        matches!(self, ListStatusType::AllDone)
+    }
+}
+
+impl fmt::Display for ListStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Response => "RESPONSE",
+                Self::ExecStarted => "EXEC_STARTED",
+                Self::AllDone => "ALL_DONE",
+                Self::Unknown(value) => value.as_str(),
+            }
+        )
+    }
+}
+
+impl FromStr for ListStatusType {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "RESPONSE" => Self::Response,
+            "EXEC_STARTED" => Self::ExecStarted,
+            "ALL_DONE" => Self::AllDone,
+            "" => return Err(value.to_owned()),
+            value => Self::Unknown(value.to_owned()),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for ListStatusType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ListStatusType;
+
+    string_enum_tests!(
+        ListStatusType,
+        "EXEC_PAUSED",
+        [
+            (ListStatusType::Response, "RESPONSE"),
+            (ListStatusType::ExecStarted, "EXEC_STARTED"),
+            (ListStatusType::AllDone, "ALL_DONE"),
+        ]
+    );
+}
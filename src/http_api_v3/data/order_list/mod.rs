@@ -0,0 +1,59 @@
+//!
+//! The order list.
+//!
+
+pub mod contingency_type;
+pub mod delete;
+pub mod get;
+pub mod list_order_status;
+pub mod list_status_type;
+
+use serde::Deserialize;
+
+use crate::http_api_v3::data::order::delete::response::Response as OrderDeleteResponse;
+
+use self::contingency_type::ContingencyType;
+use self::list_order_status::ListOrderStatus;
+use self::list_status_type::ListStatusType;
+
+///
+/// The order list data, e.g. an OCO, which is shared by the order list and open order list
+/// responses.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderList {
+    /// The server-side order list ID.
+    pub order_list_id: i64,
+    /// The order list contingency type.
+    pub contingency_type: ContingencyType,
+    /// The order list status.
+    pub list_status_type: ListStatusType,
+    /// The order list execution status.
+    pub list_order_status: ListOrderStatus,
+    /// The client-side order list ID.
+    pub list_client_order_id: String,
+    /// The order list last update time in milliseconds.
+    pub transaction_time: i64,
+    /// The symbol name.
+    pub symbol: String,
+    /// The order list orders.
+    pub orders: Vec<ListOrder>,
+    /// The cancelled order reports. Only sent on the order list cancellation.
+    #[serde(default)]
+    pub order_reports: Vec<OrderDeleteResponse>,
+}
+
+///
+/// The order list order reference.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ListOrder {
+    /// The symbol name.
+    pub symbol: String,
+    /// The server-side order ID.
+    pub order_id: i64,
+    /// The client-side order ID.
+    pub client_order_id: String,
+}
@@ -2,26 +2,71 @@
 //! The order side.
 //!
 
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de;
 use serde::Deserialize;
+use serde::Deserializer;
 
 ///
 /// The order side.
 ///
-#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum OrderSide {
     /// The buy order.
     Buy,
     /// The sell order.
     Sell,
+    /// The value unknown to this version of the library, which is kept as is.
+    Unknown(String),
+}
+
+impl fmt::Display for OrderSide {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Buy => "BUY",
+                Self::Sell => "SELL",
+                Self::Unknown(value) => value.as_str(),
+            }
+        )
+    }
 }
 
-impl ToString for OrderSide {
-    fn to_string(&self) -> String {
-        match self {
-            Self::Buy => "BUY",
-            Self::Sell => "SELL",
-        }
-        .to_owned()
+impl FromStr for OrderSide {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "BUY" => Self::Buy,
+            "SELL" => Self::Sell,
+            "" => return Err(value.to_owned()),
+            value => Self::Unknown(value.to_owned()),
+        })
     }
 }
+
+impl<'de> Deserialize<'de> for OrderSide {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OrderSide;
+
+    string_enum_tests!(
+        OrderSide,
+        "SHORT",
+        [(OrderSide::Buy, "BUY"), (OrderSide::Sell, "SELL")]
+    );
+}
@@ -2,13 +2,17 @@
 //! The order status.
 //!
 
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de;
 use serde::Deserialize;
+use serde::Deserializer;
 
 ///
 /// The order status.
 ///
-#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum OrderStatus {
     /// The order is just created.
     New,
@@ -24,20 +28,127 @@ pub enum OrderStatus {
     Rejected,
     /// The order is expired.
     Expired,
+    /// The order is expired due to the self-trade prevention.
+    ExpiredInMatch,
+    /// The value unknown to this version of the library, which is kept as is.
+    Unknown(String),
 }
 
 impl OrderStatus {
     ///
     /// A shortcut predicate.
     ///
-    pub fn is_partially_filled(self) -> bool {
+    pub fn is_partially_filled(&self) -> bool {
         matches!(self, OrderStatus::PartiallyFilled)
     }
 
     ///
     /// A shortcut predicate.
     ///
-    pub fn is_filled(self) -> bool {
+    pub fn is_filled(&self) -> bool {
         matches!(self, OrderStatus::Filled)
     }
+
+    ///
+    /// If the order is still alive on the exchange, that is, `NEW`, `PARTIALLY_FILLED`,
+    /// or `PENDING_CANCEL`.
+    ///
+    pub fn is_active(&self) -> bool {
+        matches!(
+            self,
+            OrderStatus::New | OrderStatus::PartiallyFilled | OrderStatus::PendingCancel
+        )
+    }
+
+    ///
+    /// If the order has reached its final state, that is, `FILLED`, `CANCELED`, `REJECTED`,
+    /// `EXPIRED`, or `EXPIRED_IN_MATCH`.
+    ///
+    pub fn is_done(&self) -> bool {
+        matches!(
+            self,
+            OrderStatus::Filled
+                | OrderStatus::Canceled
+                | OrderStatus::Rejected
+                | OrderStatus::Expired
+                | OrderStatus::ExpiredInMatch
+        )
+    }
+
+    ///
+    /// If the order can still receive fills, that is, `NEW` or `PARTIALLY_FILLED`.
+    ///
+    pub fn is_fillable(&self) -> bool {
+        matches!(self, OrderStatus::New | OrderStatus::PartiallyFilled)
+    }
+}
+
+impl fmt::Display for OrderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::New => "NEW",
+                Self::PartiallyFilled => "PARTIALLY_FILLED",
+                Self::Filled => "FILLED",
+                Self::Canceled => "CANCELED",
+                Self::PendingCancel => "PENDING_CANCEL",
+                Self::Rejected => "REJECTED",
+                Self::Expired => "EXPIRED",
+                Self::ExpiredInMatch => "EXPIRED_IN_MATCH",
+                Self::Unknown(value) => value.as_str(),
+            }
+        )
+    }
+}
+
+impl FromStr for OrderStatus {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "NEW" => Self::New,
+            "PARTIALLY_FILLED" => Self::PartiallyFilled,
+            "FILLED" => Self::Filled,
+            "CANCELED" => Self::Canceled,
+            "PENDING_CANCEL" => Self::PendingCancel,
+            "REJECTED" => Self::Rejected,
+            "EXPIRED" => Self::Expired,
+            "EXPIRED_IN_MATCH" => Self::ExpiredInMatch,
+            "" => return Err(value.to_owned()),
+            value => Self::Unknown(value.to_owned()),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OrderStatus;
+
+    string_enum_tests!(
+        OrderStatus,
+        "PENDING_NEW",
+        [
+            (OrderStatus::New, "NEW"),
+            (OrderStatus::PartiallyFilled, "PARTIALLY_FILLED"),
+            (OrderStatus::Filled, "FILLED"),
+            (OrderStatus::Canceled, "CANCELED"),
+            (OrderStatus::PendingCancel, "PENDING_CANCEL"),
+            (OrderStatus::Rejected, "REJECTED"),
+            (OrderStatus::Expired, "EXPIRED"),
+            (OrderStatus::ExpiredInMatch, "EXPIRED_IN_MATCH"),
+        ]
+    );
 }
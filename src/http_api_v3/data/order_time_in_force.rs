@@ -2,31 +2,79 @@
 //! The order time in force.
 //!
 
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de;
 use serde::Deserialize;
+use serde::Deserializer;
 
 ///
 /// The order time-in-force. See the below descriptions.
 ///
-#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum OrderTimeInForce {
     /// The default time-in-force. Effective until the trade is executed or cancelled.
-    #[serde(rename = "GTC")]
     GoodTilCanceled,
     /// Must be filled immediately or is cancelled.
-    #[serde(rename = "IOC")]
     ImmediateOrCancel,
     /// Is cancelled if the entire order does not execute as soon as it becomes available.
-    #[serde(rename = "FOK")]
     FillOrKill,
+    /// The value unknown to this version of the library, which is kept as is.
+    Unknown(String),
+}
+
+impl fmt::Display for OrderTimeInForce {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::GoodTilCanceled => "GTC",
+                Self::ImmediateOrCancel => "IOC",
+                Self::FillOrKill => "FOK",
+                Self::Unknown(value) => value.as_str(),
+            }
+        )
+    }
 }
 
-impl ToString for OrderTimeInForce {
-    fn to_string(&self) -> String {
-        match self {
-            Self::GoodTilCanceled => "GTC",
-            Self::ImmediateOrCancel => "IOC",
-            Self::FillOrKill => "FOK",
-        }
-        .to_owned()
+impl FromStr for OrderTimeInForce {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "GTC" => Self::GoodTilCanceled,
+            "IOC" => Self::ImmediateOrCancel,
+            "FOK" => Self::FillOrKill,
+            "" => return Err(value.to_owned()),
+            value => Self::Unknown(value.to_owned()),
+        })
     }
 }
+
+impl<'de> Deserialize<'de> for OrderTimeInForce {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OrderTimeInForce;
+
+    string_enum_tests!(
+        OrderTimeInForce,
+        "GTX",
+        [
+            (OrderTimeInForce::GoodTilCanceled, "GTC"),
+            (OrderTimeInForce::ImmediateOrCancel, "IOC"),
+            (OrderTimeInForce::FillOrKill, "FOK"),
+        ]
+    );
+}
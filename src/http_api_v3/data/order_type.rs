@@ -2,13 +2,17 @@
 //! The order type.
 //!
 
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de;
 use serde::Deserialize;
+use serde::Deserializer;
 
 ///
 /// The order time.
 ///
-#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum OrderType {
     /// The limit order.
     Limit,
@@ -24,23 +28,73 @@ pub enum OrderType {
     TakeProfitLimit,
     /// The limit-maker order. Unused for now.
     LimitMaker,
-    /// Fallback for all other variants.
-    #[serde(other)]
-    Other,
+    /// The value unknown to this version of the library, which is kept as is.
+    Unknown(String),
+}
+
+impl fmt::Display for OrderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Limit => "LIMIT",
+                Self::Market => "MARKET",
+                Self::StopLoss => "STOP_LOSS",
+                Self::StopLossLimit => "STOP_LOSS_LIMIT",
+                Self::TakeProfit => "TAKE_PROFIT",
+                Self::TakeProfitLimit => "TAKE_PROFIT_LIMIT",
+                Self::LimitMaker => "LIMIT_MAKER",
+                Self::Unknown(value) => value.as_str(),
+            }
+        )
+    }
 }
 
-impl ToString for OrderType {
-    fn to_string(&self) -> String {
-        match self {
-            Self::Limit => "LIMIT",
-            Self::Market => "MARKET",
-            Self::StopLoss => "STOP_LOSS",
-            Self::StopLossLimit => "STOP_LOSS_LIMIT",
-            Self::TakeProfit => "TAKE_PROFIT",
-            Self::TakeProfitLimit => "TAKE_PROFIT_LIMIT",
-            Self::LimitMaker => "LIMIT_MAKER",
-            Self::Other => "OTHER",
-        }
-        .to_owned()
+impl FromStr for OrderType {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "LIMIT" => Self::Limit,
+            "MARKET" => Self::Market,
+            "STOP_LOSS" => Self::StopLoss,
+            "STOP_LOSS_LIMIT" => Self::StopLossLimit,
+            "TAKE_PROFIT" => Self::TakeProfit,
+            "TAKE_PROFIT_LIMIT" => Self::TakeProfitLimit,
+            "LIMIT_MAKER" => Self::LimitMaker,
+            "" => return Err(value.to_owned()),
+            value => Self::Unknown(value.to_owned()),
+        })
     }
 }
+
+impl<'de> Deserialize<'de> for OrderType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OrderType;
+
+    string_enum_tests!(
+        OrderType,
+        "STOP_MARKET",
+        [
+            (OrderType::Limit, "LIMIT"),
+            (OrderType::Market, "MARKET"),
+            (OrderType::StopLoss, "STOP_LOSS"),
+            (OrderType::StopLossLimit, "STOP_LOSS_LIMIT"),
+            (OrderType::TakeProfit, "TAKE_PROFIT"),
+            (OrderType::TakeProfitLimit, "TAKE_PROFIT_LIMIT"),
+            (OrderType::LimitMaker, "LIMIT_MAKER"),
+        ]
+    );
+}
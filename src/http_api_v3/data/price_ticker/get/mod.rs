@@ -0,0 +1,6 @@
+//!
+//! The price ticker GET.
+//!
+
+pub mod request;
+pub mod response;
@@ -0,0 +1,82 @@
+//!
+//! The price ticker GET request.
+//!
+
+use std::fmt;
+
+use crate::http_api_v3::data::serde::serialize_symbols;
+
+///
+/// The `https://www.binance.com/api/v3/ticker/price` GET request query.
+///
+/// If neither `symbol` nor `symbols` is set, the tickers of all symbols are returned.
+///
+#[derive(Debug)]
+pub struct Query {
+    /// The single symbol name.
+    pub symbol: Option<String>,
+    /// The symbol names, which are fetched in one request. Mutually exclusive with `symbol`.
+    pub symbols: Option<Vec<String>>,
+}
+
+impl Query {
+    /// The query params default capacity.
+    const QUERY_INITIAL_CAPACITY: usize = 256;
+
+    ///
+    /// A shortcut constructor of the single symbol query.
+    ///
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: Some(symbol.to_owned()),
+            symbols: None,
+        }
+    }
+
+    ///
+    /// A shortcut constructor of the query of several symbols.
+    ///
+    /// The response contains a ticker for each symbol.
+    ///
+    pub fn new_batch(symbols: Vec<String>) -> Self {
+        Self {
+            symbol: None,
+            symbols: Some(symbols),
+        }
+    }
+
+    ///
+    /// A shortcut constructor of the query of all symbols.
+    ///
+    pub fn all() -> Self {
+        Self {
+            symbol: None,
+            symbols: None,
+        }
+    }
+
+    ///
+    /// The request weight, which is 2 for a single symbol and 4 otherwise.
+    ///
+    pub fn weight(&self) -> u32 {
+        if self.symbol.is_some() {
+            2
+        } else {
+            4
+        }
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut params =
+            form_urlencoded::Serializer::new(String::with_capacity(Self::QUERY_INITIAL_CAPACITY));
+        if let Some(ref symbol) = self.symbol {
+            params.append_pair("symbol", symbol.as_str());
+        }
+        if let Some(ref symbols) = self.symbols {
+            params.append_pair("symbols", &serialize_symbols(symbols.as_slice()));
+        }
+        write!(f, "{}", params.finish())
+    }
+}
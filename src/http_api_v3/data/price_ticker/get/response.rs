@@ -0,0 +1,47 @@
+//!
+//! The price ticker GET response.
+//!
+
+use serde::Deserialize;
+
+use crate::http_api_v3::data::serde::deserialize_price;
+use crate::http_api_v3::data::serde::PriceType;
+
+///
+/// The `https://www.binance.com/api/v3/ticker/price` GET response.
+///
+/// A single ticker is returned for the `symbol` query, and a list otherwise.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum Response {
+    /// The ticker of the single requested symbol.
+    Single(PriceTicker),
+    /// The tickers of several or all symbols.
+    Multiple(Vec<PriceTicker>),
+}
+
+impl Response {
+    ///
+    /// Converts the response into a list of tickers.
+    ///
+    pub fn into_vec(self) -> Vec<PriceTicker> {
+        match self {
+            Self::Single(ticker) => vec![ticker],
+            Self::Multiple(tickers) => tickers,
+        }
+    }
+}
+
+///
+/// The latest price of a symbol.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceTicker {
+    /// The symbol name.
+    pub symbol: String,
+    /// The latest price.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub price: PriceType,
+}
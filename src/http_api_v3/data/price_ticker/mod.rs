@@ -0,0 +1,5 @@
+//!
+//! The price ticker.
+//!
+
+pub mod get;
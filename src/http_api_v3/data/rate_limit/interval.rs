@@ -0,0 +1,37 @@
+//!
+//! The rate limit interval.
+//!
+
+use std::time::Duration;
+
+use serde::Deserialize;
+
+///
+/// The rate limit time window unit.
+///
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RateLimitInterval {
+    /// The 1 second unit.
+    Second,
+    /// The 1 minute unit.
+    Minute,
+    /// The 1 hour unit.
+    Hour,
+    /// The 1 day unit.
+    Day,
+}
+
+impl RateLimitInterval {
+    ///
+    /// The time window unit duration.
+    ///
+    pub fn duration(self) -> Duration {
+        Duration::from_secs(match self {
+            Self::Second => 1,
+            Self::Minute => 60,
+            Self::Hour => 3600,
+            Self::Day => 86400,
+        })
+    }
+}
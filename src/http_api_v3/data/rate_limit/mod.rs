@@ -0,0 +1,39 @@
+//!
+//! The rate limit.
+//!
+
+pub mod interval;
+pub mod r#type;
+
+use serde::Deserialize;
+
+use self::interval::RateLimitInterval;
+use self::r#type::RateLimitType;
+
+///
+/// The Binance rate limit, which is applied to a rolling time window.
+///
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimit {
+    /// The rate limit type.
+    pub rate_limit_type: RateLimitType,
+    /// The rate limit time window unit.
+    pub interval: RateLimitInterval,
+    /// The number of time window units.
+    pub interval_num: u32,
+    /// The maximal usage allowed within the time window.
+    pub limit: u32,
+    /// The current usage within the time window. Only returned by the order rate limit usage.
+    #[serde(default)]
+    pub count: Option<u32>,
+}
+
+impl RateLimit {
+    ///
+    /// The rate limit time window duration.
+    ///
+    pub fn window(&self) -> std::time::Duration {
+        self.interval.duration() * self.interval_num
+    }
+}
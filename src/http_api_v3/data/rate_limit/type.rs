@@ -0,0 +1,22 @@
+//!
+//! The rate limit type.
+//!
+
+use serde::Deserialize;
+
+///
+/// The rate limit type.
+///
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RateLimitType {
+    /// The sum of request weights.
+    RequestWeight,
+    /// The number of placed orders.
+    Orders,
+    /// The number of requests regardless of their weight.
+    RawRequests,
+    /// Fallback for all other variants.
+    #[serde(other)]
+    Other,
+}
@@ -0,0 +1,6 @@
+//!
+//! The order rate limit usage GET.
+//!
+
+pub mod request;
+pub mod response;
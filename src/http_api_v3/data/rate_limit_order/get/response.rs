@@ -0,0 +1,12 @@
+//!
+//! The order rate limit usage GET response.
+//!
+
+use crate::http_api_v3::data::rate_limit::RateLimit;
+
+///
+/// The `https://www.binance.com/api/v3/rateLimit/order` GET response.
+///
+/// Each element has the `count` of the orders placed within its time window.
+///
+pub type Response = Vec<RateLimit>;
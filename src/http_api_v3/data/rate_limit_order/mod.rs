@@ -0,0 +1,5 @@
+//!
+//! The order rate limit usage.
+//!
+
+pub mod get;
@@ -0,0 +1,6 @@
+//!
+//! The deposit history GET.
+//!
+
+pub mod request;
+pub mod response;
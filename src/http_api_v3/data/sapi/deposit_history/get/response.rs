@@ -0,0 +1,44 @@
+//!
+//! The deposit history GET response.
+//!
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::http_api_v3::data::serde::deserialize_decimal;
+
+///
+/// The `https://www.binance.com/sapi/v1/capital/deposit/hisrec` GET response.
+///
+pub type Response = Vec<Deposit>;
+
+///
+/// A single deposit record.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Deposit {
+    /// The deposit amount.
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub amount: Decimal,
+    /// The coin name.
+    pub coin: String,
+    /// The blockchain network name.
+    #[serde(default)]
+    pub network: String,
+    /// The deposit status: `0` is pending, `6` is credited but cannot be withdrawn yet, `7` is a
+    /// wrong deposit, `8` is waiting for the user confirmation, `1` is success, `2` is rejected.
+    pub status: i64,
+    /// The deposit address.
+    pub address: String,
+    /// The deposit address tag or memo.
+    #[serde(default)]
+    pub address_tag: String,
+    /// The blockchain transaction ID.
+    pub tx_id: String,
+    /// The deposit detection time in milliseconds.
+    pub insert_time: i64,
+    /// The deposit completion time in milliseconds, if completed.
+    #[serde(default)]
+    pub complete_time: Option<i64>,
+}
@@ -0,0 +1,5 @@
+//!
+//! The deposit history.
+//!
+
+pub mod get;
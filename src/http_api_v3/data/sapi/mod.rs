@@ -0,0 +1,7 @@
+//!
+//! The Binance SAPI data structures, which share the authorization with API v3.
+//!
+
+pub mod deposit_history;
+pub mod system_status;
+pub mod withdraw_history;
@@ -0,0 +1,5 @@
+//!
+//! The system status GET.
+//!
+
+pub mod response;
@@ -0,0 +1,27 @@
+//!
+//! The system status GET response.
+//!
+
+use serde::Deserialize;
+
+use crate::http_api_v3::data::sapi::system_status::status::Status;
+
+///
+/// The `https://www.binance.com/sapi/v1/system/status` GET response.
+///
+#[derive(Debug, Deserialize, Clone)]
+pub struct Response {
+    /// The system status.
+    pub status: Status,
+    /// The status message, e.g. `normal` or `system_maintenance`.
+    pub msg: String,
+}
+
+impl Response {
+    ///
+    /// If the system works normally, so the trading may go on.
+    ///
+    pub fn is_normal(&self) -> bool {
+        matches!(self.status, Status::Normal)
+    }
+}
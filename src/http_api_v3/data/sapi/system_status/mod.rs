@@ -0,0 +1,6 @@
+//!
+//! The system status.
+//!
+
+pub mod get;
+pub mod status;
@@ -0,0 +1,29 @@
+//!
+//! The system status.
+//!
+
+use serde::Deserialize;
+
+///
+/// The system status, which Binance sends as a number.
+///
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(from = "i64")]
+pub enum Status {
+    /// The system works normally.
+    Normal,
+    /// The system is under maintenance, so the trading should be paused.
+    Maintenance,
+    /// Fallback for all other variants.
+    Other,
+}
+
+impl From<i64> for Status {
+    fn from(value: i64) -> Self {
+        match value {
+            0 => Self::Normal,
+            1 => Self::Maintenance,
+            _ => Self::Other,
+        }
+    }
+}
@@ -0,0 +1,6 @@
+//!
+//! The withdraw history GET.
+//!
+
+pub mod request;
+pub mod response;
@@ -0,0 +1,117 @@
+//!
+//! The withdraw history GET request.
+//!
+
+use std::fmt;
+
+use crate::error::Error;
+use crate::http_api_v3::data::time_range;
+use crate::http_api_v3::signed_query::SignedQuery;
+
+///
+/// The `https://www.binance.com/sapi/v1/capital/withdraw/history` GET request query.
+///
+#[derive(Debug)]
+pub struct Query {
+    /// The coin name, e.g. `BTC`.
+    pub coin: Option<String>,
+    /// The client-side withdrawal ID.
+    pub withdraw_order_id: Option<String>,
+    /// The withdrawal status. See `Withdrawal::status`.
+    pub status: Option<i64>,
+    /// The history start time in milliseconds. Defaults to 90 days ago.
+    pub start_time: Option<i64>,
+    /// The history end time in milliseconds. Defaults to now.
+    pub end_time: Option<i64>,
+    /// The number of records to skip.
+    pub offset: Option<usize>,
+    /// The maximal number of records. Default is 1000, maximum is 1000.
+    pub limit: Option<usize>,
+    /// The allowed time window between the request and response in milliseconds.
+    pub recv_window: Option<i64>,
+    /// The request time in milliseconds, which is set by the client on signing.
+    pub timestamp: i64,
+}
+
+impl Default for Query {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Query {
+    /// The query params default capacity.
+    const QUERY_INITIAL_CAPACITY: usize = 256;
+    /// The maximal time range in milliseconds, which is 90 days.
+    pub const TIME_RANGE_MAX: i64 = 90 * time_range::DAY_MS;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new() -> Self {
+        Self {
+            coin: None,
+            withdraw_order_id: None,
+            status: None,
+            start_time: None,
+            end_time: None,
+            offset: None,
+            limit: None,
+            recv_window: None,
+            timestamp: 0,
+        }
+    }
+
+    ///
+    /// Checks that the time range is not inverted and is at most `TIME_RANGE_MAX`.
+    ///
+    pub fn validate(&self) -> Result<(), Error> {
+        time_range::validate(self.start_time, self.end_time, Some(Self::TIME_RANGE_MAX))
+    }
+
+    ///
+    /// The request weight, which is zero, since the SAPI limits are separate from the API v3 ones.
+    ///
+    pub fn weight(&self) -> u32 {
+        0
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut params =
+            form_urlencoded::Serializer::new(String::with_capacity(Self::QUERY_INITIAL_CAPACITY));
+        if let Some(ref coin) = self.coin {
+            params.append_pair("coin", coin.as_str());
+        }
+        if let Some(ref withdraw_order_id) = self.withdraw_order_id {
+            params.append_pair("withdrawOrderId", withdraw_order_id.as_str());
+        }
+        if let Some(status) = self.status {
+            params.append_pair("status", &status.to_string());
+        }
+        if let Some(start_time) = self.start_time {
+            params.append_pair("startTime", &start_time.to_string());
+        }
+        if let Some(end_time) = self.end_time {
+            params.append_pair("endTime", &end_time.to_string());
+        }
+        if let Some(offset) = self.offset {
+            params.append_pair("offset", &offset.to_string());
+        }
+        if let Some(limit) = self.limit {
+            params.append_pair("limit", &limit.to_string());
+        }
+        if let Some(recv_window) = self.recv_window {
+            params.append_pair("recvWindow", &recv_window.to_string());
+        }
+        params.append_pair("timestamp", &self.timestamp.to_string());
+        write!(f, "{}", params.finish())
+    }
+}
+
+impl SignedQuery for Query {
+    fn set_timestamp(&mut self, timestamp: i64) {
+        self.timestamp = timestamp;
+    }
+}
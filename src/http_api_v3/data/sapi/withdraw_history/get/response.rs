@@ -0,0 +1,50 @@
+//!
+//! The withdraw history GET response.
+//!
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::http_api_v3::data::serde::deserialize_decimal;
+
+///
+/// The `https://www.binance.com/sapi/v1/capital/withdraw/history` GET response.
+///
+pub type Response = Vec<Withdrawal>;
+
+///
+/// A single withdrawal record.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Withdrawal {
+    /// The server-side withdrawal ID.
+    pub id: String,
+    /// The withdrawal amount.
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub amount: Decimal,
+    /// The withdrawal fee.
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub transaction_fee: Decimal,
+    /// The coin name.
+    pub coin: String,
+    /// The withdrawal status: `0` is the email sent, `2` is awaiting approval, `3` is rejected,
+    /// `4` is processing, `6` is completed.
+    pub status: i64,
+    /// The withdrawal address.
+    pub address: String,
+    /// The blockchain transaction ID, once the withdrawal is sent.
+    #[serde(default)]
+    pub tx_id: Option<String>,
+    /// The withdrawal request time in the `YYYY-MM-DD HH:MM:SS` UTC format.
+    pub apply_time: String,
+    /// The withdrawal completion time in the `YYYY-MM-DD HH:MM:SS` UTC format, if completed.
+    #[serde(default)]
+    pub complete_time: Option<String>,
+    /// The blockchain network name.
+    #[serde(default)]
+    pub network: String,
+    /// The client-side withdrawal ID, if set.
+    #[serde(default)]
+    pub withdraw_order_id: Option<String>,
+}
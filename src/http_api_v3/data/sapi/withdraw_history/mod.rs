@@ -0,0 +1,5 @@
+//!
+//! The withdraw history.
+//!
+
+pub mod get;
@@ -0,0 +1,46 @@
+//!
+//! The self-trade prevention mode.
+//!
+
+use std::fmt;
+
+use serde::Deserialize;
+
+///
+/// The self-trade prevention mode, which defines what happens if the order would match another
+/// order of the same account or trade group.
+///
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SelfTradePreventionMode {
+    /// The self-trades are allowed.
+    None,
+    /// The taker order is expired.
+    ExpireTaker,
+    /// The maker order is expired.
+    ExpireMaker,
+    /// Both the taker and maker orders are expired.
+    ExpireBoth,
+    /// Both orders are decreased by the prevented quantity.
+    Decrement,
+    /// Fallback for all other variants.
+    #[serde(other)]
+    Other,
+}
+
+impl fmt::Display for SelfTradePreventionMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::None => "NONE",
+                Self::ExpireTaker => "EXPIRE_TAKER",
+                Self::ExpireMaker => "EXPIRE_MAKER",
+                Self::ExpireBoth => "EXPIRE_BOTH",
+                Self::Decrement => "DECREMENT",
+                Self::Other => "OTHER",
+            }
+        )
+    }
+}
@@ -0,0 +1,272 @@
+//!
+//! The Binance API v3 data serialization and deserialization helpers.
+//!
+
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use serde::de;
+use serde::Deserialize;
+use serde::Deserializer;
+
+///
+/// The market data price and quantity type.
+///
+/// Defaults to the precise `Decimal`, and is switched to `f64` by the `f64-prices` feature.
+///
+#[cfg(not(feature = "f64-prices"))]
+pub type PriceType = Decimal;
+
+///
+/// The market data price and quantity type.
+///
+/// Defaults to the precise `Decimal`, and is switched to `f64` by the `f64-prices` feature.
+///
+#[cfg(feature = "f64-prices")]
+pub type PriceType = f64;
+
+///
+/// The decimal value, which Binance usually sends as a string, but sometimes as a number.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum StringOrNumber {
+    /// The string value, e.g. `"1.23"`.
+    String(String),
+    /// The number value, e.g. `1.23`.
+    Number(serde_json::Number),
+}
+
+impl StringOrNumber {
+    ///
+    /// Converts the value into its string representation.
+    ///
+    pub fn into_string(self) -> String {
+        match self {
+            Self::String(value) => value,
+            Self::Number(value) => value.to_string(),
+        }
+    }
+}
+
+///
+/// Deserializes a decimal string or number.
+///
+pub fn deserialize_decimal<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = StringOrNumber::deserialize(deserializer)?.into_string();
+    parse_decimal(value.as_str()).map_err(de::Error::custom)
+}
+
+///
+/// Deserializes an optional decimal string or number, treating `null`, `""`, and, if used
+/// together with `#[serde(default)]`, a missing field as `None`.
+///
+pub fn deserialize_optional_decimal<'de, D>(deserializer: D) -> Result<Option<Decimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<StringOrNumber>::deserialize(deserializer)?.map(StringOrNumber::into_string) {
+        Some(value) if !value.is_empty() => parse_decimal(value.as_str())
+            .map(Some)
+            .map_err(de::Error::custom),
+        _ => Ok(None),
+    }
+}
+
+///
+/// Deserializes a price or quantity string or number into the selected `PriceType`.
+///
+pub fn deserialize_price<'de, D>(deserializer: D) -> Result<PriceType, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = StringOrNumber::deserialize(deserializer)?.into_string();
+    parse_price(value.as_str()).map_err(de::Error::custom)
+}
+
+///
+/// Deserializes an optional price or quantity string or number into the selected `PriceType`,
+/// treating `null`, `""`, and, if used together with `#[serde(default)]`, a missing field as
+/// `None`.
+///
+pub fn deserialize_optional_price<'de, D>(deserializer: D) -> Result<Option<PriceType>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<StringOrNumber>::deserialize(deserializer)?.map(StringOrNumber::into_string) {
+        Some(value) if !value.is_empty() => parse_price(value.as_str())
+            .map(Some)
+            .map_err(de::Error::custom),
+        _ => Ok(None),
+    }
+}
+
+///
+/// Parses a decimal string, including the scientific notation of the JSON numbers, e.g. `1e-7`.
+///
+pub fn parse_decimal(value: &str) -> Result<Decimal, String> {
+    if value.contains(['e', 'E']) {
+        Decimal::from_scientific(value)
+    } else {
+        Decimal::from_str(value)
+    }
+    .map_err(|error| format!("invalid decimal `{}`: {}", value, error))
+}
+
+///
+/// Parses a price or quantity string into the selected `PriceType`.
+///
+#[cfg(not(feature = "f64-prices"))]
+pub fn parse_price(value: &str) -> Result<PriceType, String> {
+    parse_decimal(value)
+}
+
+///
+/// Parses a price or quantity string into the selected `PriceType`.
+///
+#[cfg(feature = "f64-prices")]
+pub fn parse_price(value: &str) -> Result<PriceType, String> {
+    PriceType::from_str(value).map_err(|error| format!("invalid price `{}`: {}", value, error))
+}
+
+///
+/// Serializes the symbol names into the JSON array, as expected by the `symbols` query parameter,
+/// e.g. `["BTCUSDT","ETHUSDT"]`. The array is percent-encoded by the query serializer.
+///
+pub fn serialize_symbols(symbols: &[String]) -> String {
+    serde_json::to_string(symbols).expect("Strings are serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use serde::Deserialize;
+
+    use super::parse_decimal;
+    use super::PriceType;
+    use super::StringOrNumber;
+
+    #[derive(Debug, Deserialize)]
+    struct RequiredDecimal {
+        #[serde(deserialize_with = "super::deserialize_decimal")]
+        value: Decimal,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct OptionalDecimal {
+        #[serde(default, deserialize_with = "super::deserialize_optional_decimal")]
+        value: Option<Decimal>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct OptionalPrice {
+        #[serde(default, deserialize_with = "super::deserialize_optional_price")]
+        value: Option<PriceType>,
+    }
+
+    fn parse(json: &str) -> Option<Decimal> {
+        serde_json::from_str::<OptionalDecimal>(json)
+            .expect("Valid JSON")
+            .value
+    }
+
+    fn parse_price(json: &str) -> Option<PriceType> {
+        serde_json::from_str::<OptionalPrice>(json)
+            .expect("Valid JSON")
+            .value
+    }
+
+    #[test]
+    fn optional_decimal_null_is_none() {
+        assert_eq!(parse(r#"{"value":null}"#), None);
+    }
+
+    #[test]
+    fn optional_decimal_empty_string_is_none() {
+        assert_eq!(parse(r#"{"value":""}"#), None);
+    }
+
+    #[test]
+    fn optional_decimal_missing_is_none() {
+        assert_eq!(parse(r#"{}"#), None);
+    }
+
+    #[test]
+    fn optional_decimal_value_is_parsed() {
+        assert_eq!(parse(r#"{"value":"1.5"}"#), Some(Decimal::new(15, 1)));
+    }
+
+    #[test]
+    fn optional_price_null_is_none() {
+        assert_eq!(parse_price(r#"{"value":null}"#), None);
+    }
+
+    #[test]
+    fn optional_price_empty_string_is_none() {
+        assert_eq!(parse_price(r#"{"value":""}"#), None);
+    }
+
+    #[test]
+    fn optional_price_missing_is_none() {
+        assert_eq!(parse_price(r#"{}"#), None);
+    }
+
+    #[test]
+    fn optional_price_value_is_parsed() {
+        assert_eq!(
+            parse_price(r#"{"value":"1.5"}"#),
+            Some("1.5".parse::<PriceType>().expect("Valid price"))
+        );
+    }
+
+    #[test]
+    fn string_or_number_accepts_string() {
+        let value = serde_json::from_str::<StringOrNumber>(r#""1.23""#).expect("Valid JSON");
+        assert_eq!(value.into_string(), "1.23");
+    }
+
+    #[test]
+    fn string_or_number_accepts_number() {
+        let value = serde_json::from_str::<StringOrNumber>("1.23").expect("Valid JSON");
+        assert_eq!(value.into_string(), "1.23");
+    }
+
+    #[test]
+    fn decimal_string_is_parsed() {
+        let value = serde_json::from_str::<RequiredDecimal>(r#"{"value":"0.00100000"}"#)
+            .expect("Valid JSON")
+            .value;
+        assert_eq!(value, Decimal::new(1, 3));
+    }
+
+    #[test]
+    fn decimal_number_is_parsed() {
+        let value = serde_json::from_str::<RequiredDecimal>(r#"{"value":0.001}"#)
+            .expect("Valid JSON")
+            .value;
+        assert_eq!(value, Decimal::new(1, 3));
+    }
+
+    #[test]
+    fn decimal_invalid_is_rejected() {
+        assert!(serde_json::from_str::<RequiredDecimal>(r#"{"value":"one"}"#).is_err());
+        assert!(serde_json::from_str::<RequiredDecimal>(r#"{"value":true}"#).is_err());
+    }
+
+    #[test]
+    fn parse_decimal_accepts_plain_and_scientific() {
+        assert_eq!(parse_decimal("1.23"), Ok(Decimal::new(123, 2)));
+        assert_eq!(parse_decimal("1e-7"), Ok(Decimal::new(1, 7)));
+        assert_eq!(parse_decimal("2.5E2"), Ok(Decimal::new(250, 0)));
+    }
+
+    #[test]
+    fn parse_decimal_rejects_invalid() {
+        assert!(parse_decimal("").is_err());
+        assert!(parse_decimal("1.2.3").is_err());
+        assert!(parse_decimal("1e").is_err());
+    }
+}
@@ -0,0 +1,6 @@
+//!
+//! The 24hr ticker GET.
+//!
+
+pub mod request;
+pub mod response;
@@ -0,0 +1,87 @@
+//!
+//! The 24hr ticker GET request.
+//!
+
+use std::fmt;
+
+use crate::http_api_v3::data::serde::serialize_symbols;
+
+///
+/// The `https://www.binance.com/api/v3/ticker/24hr` GET request query.
+///
+/// If neither `symbol` nor `symbols` is set, the tickers of all symbols are returned.
+///
+#[derive(Debug)]
+pub struct Query {
+    /// The single symbol name.
+    pub symbol: Option<String>,
+    /// The symbol names, which are fetched in one request. Mutually exclusive with `symbol`.
+    pub symbols: Option<Vec<String>>,
+}
+
+impl Query {
+    /// The query params default capacity.
+    const QUERY_INITIAL_CAPACITY: usize = 256;
+
+    ///
+    /// A shortcut constructor of the single symbol query.
+    ///
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: Some(symbol.to_owned()),
+            symbols: None,
+        }
+    }
+
+    ///
+    /// A shortcut constructor of the query of several symbols.
+    ///
+    /// The response contains a ticker for each symbol.
+    ///
+    pub fn new_batch(symbols: Vec<String>) -> Self {
+        Self {
+            symbol: None,
+            symbols: Some(symbols),
+        }
+    }
+
+    ///
+    /// A shortcut constructor of the query of all symbols.
+    ///
+    pub fn all() -> Self {
+        Self {
+            symbol: None,
+            symbols: None,
+        }
+    }
+
+    ///
+    /// The request weight, which depends on the number of symbols:
+    ///
+    /// - 1 to 20: 2
+    /// - 21 to 100: 40
+    /// - 101 or more, or all symbols: 80
+    ///
+    pub fn weight(&self) -> u32 {
+        match (&self.symbol, &self.symbols) {
+            (Some(_), _) => 2,
+            (None, Some(symbols)) if symbols.len() <= 20 => 2,
+            (None, Some(symbols)) if symbols.len() <= 100 => 40,
+            _ => 80,
+        }
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut params =
+            form_urlencoded::Serializer::new(String::with_capacity(Self::QUERY_INITIAL_CAPACITY));
+        if let Some(ref symbol) = self.symbol {
+            params.append_pair("symbol", symbol.as_str());
+        }
+        if let Some(ref symbols) = self.symbols {
+            params.append_pair("symbols", &serialize_symbols(symbols.as_slice()));
+        }
+        write!(f, "{}", params.finish())
+    }
+}
@@ -0,0 +1,99 @@
+//!
+//! The 24hr ticker GET response.
+//!
+
+use serde::Deserialize;
+
+use crate::http_api_v3::data::serde::deserialize_price;
+use crate::http_api_v3::data::serde::PriceType;
+
+///
+/// The `https://www.binance.com/api/v3/ticker/24hr` GET response.
+///
+/// A single ticker is returned for the `symbol` query, and a list otherwise.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum Response {
+    /// The ticker of the single requested symbol.
+    Single(Box<Ticker>),
+    /// The tickers of several or all symbols.
+    Multiple(Vec<Ticker>),
+}
+
+impl Response {
+    ///
+    /// Converts the response into a list of tickers.
+    ///
+    pub fn into_vec(self) -> Vec<Ticker> {
+        match self {
+            Self::Single(ticker) => vec![*ticker],
+            Self::Multiple(tickers) => tickers,
+        }
+    }
+}
+
+///
+/// The 24hr rolling window price change statistics of a symbol.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Ticker {
+    /// The symbol name.
+    pub symbol: String,
+    /// The absolute price change.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub price_change: PriceType,
+    /// The relative price change in percent.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub price_change_percent: PriceType,
+    /// The volume weighted average price.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub weighted_avg_price: PriceType,
+    /// The last price before the window.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub prev_close_price: PriceType,
+    /// The last trade price.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub last_price: PriceType,
+    /// The last trade quantity.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub last_qty: PriceType,
+    /// The best bid price.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub bid_price: PriceType,
+    /// The best bid quantity.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub bid_qty: PriceType,
+    /// The best ask price.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub ask_price: PriceType,
+    /// The best ask quantity.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub ask_qty: PriceType,
+    /// The first trade price within the window.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub open_price: PriceType,
+    /// The highest price within the window.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub high_price: PriceType,
+    /// The lowest price within the window.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub low_price: PriceType,
+    /// The volume in secondary token.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub volume: PriceType,
+    /// The volume in primary token.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub quote_volume: PriceType,
+    /// The window open time in milliseconds since Unix epoch.
+    pub open_time: i64,
+    /// The window close time in milliseconds since Unix epoch.
+    pub close_time: i64,
+    /// The first trade ID within the window.
+    pub first_id: i64,
+    /// The last trade ID within the window.
+    pub last_id: i64,
+    /// The number of trades within the window.
+    pub count: i64,
+}
@@ -0,0 +1,5 @@
+//!
+//! The 24hr ticker.
+//!
+
+pub mod get;
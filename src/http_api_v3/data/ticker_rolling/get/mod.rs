@@ -0,0 +1,6 @@
+//!
+//! The rolling window ticker GET.
+//!
+
+pub mod request;
+pub mod response;
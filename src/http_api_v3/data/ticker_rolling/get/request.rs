@@ -0,0 +1,89 @@
+//!
+//! The rolling window ticker GET request.
+//!
+
+use std::fmt;
+
+use crate::http_api_v3::data::serde::serialize_symbols;
+use crate::http_api_v3::data::ticker_type::TickerType;
+
+///
+/// The `https://www.binance.com/api/v3/ticker` GET request query.
+///
+/// Either `symbol` or `symbols` must be set.
+///
+#[derive(Debug)]
+pub struct Query {
+    /// The single symbol name.
+    pub symbol: Option<String>,
+    /// The symbol names, which are fetched in one request. Mutually exclusive with `symbol`.
+    pub symbols: Option<Vec<String>>,
+    /// The window size, e.g. `1h` or `4h`. Supports `1m`-`59m`, `1h`-`23h`, and `1d`-`7d`.
+    /// `1d` by default.
+    pub window_size: Option<String>,
+    /// The response type. `FULL` by default.
+    pub r#type: Option<TickerType>,
+}
+
+impl Query {
+    /// The query params default capacity.
+    const QUERY_INITIAL_CAPACITY: usize = 256;
+
+    ///
+    /// A shortcut constructor of the single symbol query.
+    ///
+    pub fn new(symbol: &str, window_size: &str) -> Self {
+        Self {
+            symbol: Some(symbol.to_owned()),
+            symbols: None,
+            window_size: Some(window_size.to_owned()),
+            r#type: None,
+        }
+    }
+
+    ///
+    /// A shortcut constructor of the query of several symbols.
+    ///
+    /// The response contains a ticker for each symbol.
+    ///
+    pub fn new_batch(symbols: Vec<String>, window_size: &str) -> Self {
+        Self {
+            symbol: None,
+            symbols: Some(symbols),
+            window_size: Some(window_size.to_owned()),
+            r#type: None,
+        }
+    }
+
+    ///
+    /// The request weight, which is 4 per symbol, capped at 200.
+    ///
+    pub fn weight(&self) -> u32 {
+        let symbols = match (&self.symbol, &self.symbols) {
+            (Some(_), _) => 1,
+            (None, Some(symbols)) => symbols.len() as u32,
+            (None, None) => 0,
+        };
+        (4 * symbols).min(200)
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut params =
+            form_urlencoded::Serializer::new(String::with_capacity(Self::QUERY_INITIAL_CAPACITY));
+        if let Some(ref symbol) = self.symbol {
+            params.append_pair("symbol", symbol.as_str());
+        }
+        if let Some(ref symbols) = self.symbols {
+            params.append_pair("symbols", &serialize_symbols(symbols.as_slice()));
+        }
+        if let Some(ref window_size) = self.window_size {
+            params.append_pair("windowSize", window_size.as_str());
+        }
+        if let Some(r#type) = self.r#type {
+            params.append_pair("type", &r#type.to_string());
+        }
+        write!(f, "{}", params.finish())
+    }
+}
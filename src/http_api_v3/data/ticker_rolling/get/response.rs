@@ -0,0 +1,84 @@
+//!
+//! The rolling window ticker GET response.
+//!
+
+use serde::Deserialize;
+
+use crate::http_api_v3::data::serde::deserialize_optional_price;
+use crate::http_api_v3::data::serde::deserialize_price;
+use crate::http_api_v3::data::serde::PriceType;
+
+///
+/// The `https://www.binance.com/api/v3/ticker` GET response.
+///
+/// A single ticker is returned for the `symbol` query, and a list for the `symbols` one.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum Response {
+    /// The ticker of the single requested symbol.
+    Single(Box<RollingTicker>),
+    /// The tickers of the requested symbols.
+    Multiple(Vec<RollingTicker>),
+}
+
+impl Response {
+    ///
+    /// Converts the response into a list of tickers.
+    ///
+    pub fn into_vec(self) -> Vec<RollingTicker> {
+        match self {
+            Self::Single(ticker) => vec![*ticker],
+            Self::Multiple(tickers) => tickers,
+        }
+    }
+}
+
+///
+/// The rolling window price change statistics of a symbol.
+///
+/// Unlike the 24hr ticker, it has neither the previous close nor the best bid and ask.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RollingTicker {
+    /// The symbol name.
+    pub symbol: String,
+    /// The absolute price change. Absent in the `MINI` response.
+    #[serde(default, deserialize_with = "deserialize_optional_price")]
+    pub price_change: Option<PriceType>,
+    /// The relative price change in percent. Absent in the `MINI` response.
+    #[serde(default, deserialize_with = "deserialize_optional_price")]
+    pub price_change_percent: Option<PriceType>,
+    /// The volume weighted average price. Absent in the `MINI` response.
+    #[serde(default, deserialize_with = "deserialize_optional_price")]
+    pub weighted_avg_price: Option<PriceType>,
+    /// The first trade price within the window.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub open_price: PriceType,
+    /// The highest price within the window.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub high_price: PriceType,
+    /// The lowest price within the window.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub low_price: PriceType,
+    /// The last trade price.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub last_price: PriceType,
+    /// The volume in secondary token.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub volume: PriceType,
+    /// The volume in primary token.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub quote_volume: PriceType,
+    /// The window open time in milliseconds since Unix epoch.
+    pub open_time: i64,
+    /// The window close time in milliseconds since Unix epoch.
+    pub close_time: i64,
+    /// The first trade ID within the window.
+    pub first_id: i64,
+    /// The last trade ID within the window.
+    pub last_id: i64,
+    /// The number of trades within the window.
+    pub count: i64,
+}
@@ -0,0 +1,5 @@
+//!
+//! The rolling window ticker.
+//!
+
+pub mod get;
@@ -0,0 +1,25 @@
+//!
+//! The ticker type.
+//!
+
+use std::fmt;
+
+///
+/// The ticker response type.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TickerType {
+    /// The full ticker with the price change statistics.
+    Full,
+    /// The ticker without the price change statistics.
+    Mini,
+}
+
+impl fmt::Display for TickerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Full => write!(f, "FULL"),
+            Self::Mini => write!(f, "MINI"),
+        }
+    }
+}
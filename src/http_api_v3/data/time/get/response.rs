@@ -2,6 +2,7 @@
 //! The time GET response.
 //!
 
+use chrono::prelude::*;
 use serde::Deserialize;
 
 ///
@@ -13,3 +14,14 @@ pub struct Response {
     /// The server time in milliseconds since Unix epoch.
     pub server_time: i64,
 }
+
+impl Response {
+    ///
+    /// The server time as a date and time.
+    ///
+    pub fn as_datetime(&self) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(self.server_time)
+            .single()
+            .expect("Binance timestamps are within the date and time range")
+    }
+}
@@ -0,0 +1,76 @@
+//!
+//! The request time range validation.
+//!
+
+use crate::error::Error;
+
+/// The number of milliseconds in a day.
+pub const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+///
+/// Checks that `start_time` is not after `end_time`, and that the range between them is at most
+/// `max_range` milliseconds, if both boundaries are set.
+///
+/// Binance rejects such requests, so the signed ones are not sent in vain.
+///
+pub fn validate(
+    start_time: Option<i64>,
+    end_time: Option<i64>,
+    max_range: Option<i64>,
+) -> Result<(), Error> {
+    let (start_time, end_time) = match (start_time, end_time) {
+        (Some(start_time), Some(end_time)) => (start_time, end_time),
+        _ => return Ok(()),
+    };
+
+    if start_time > end_time {
+        return Err(Error::InvalidRequest(format!(
+            "start time {} is after end time {}",
+            start_time, end_time
+        )));
+    }
+    if let Some(max_range) = max_range {
+        if end_time - start_time > max_range {
+            return Err(Error::InvalidRequest(format!(
+                "time range {}..{} exceeds the maximum of {} ms",
+                start_time, end_time, max_range
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+
+    use super::validate;
+    use super::DAY_MS;
+
+    #[test]
+    fn inverted_range_is_rejected() {
+        match validate(Some(DAY_MS), Some(0), None) {
+            Err(Error::InvalidRequest(_)) => {}
+            result => panic!("expected InvalidRequest, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn oversized_range_is_rejected() {
+        match validate(Some(0), Some(DAY_MS + 1), Some(DAY_MS)) {
+            Err(Error::InvalidRequest(_)) => {}
+            result => panic!("expected InvalidRequest, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn maximal_range_passes() {
+        assert!(validate(Some(0), Some(DAY_MS), Some(DAY_MS)).is_ok());
+    }
+
+    #[test]
+    fn open_range_passes() {
+        assert!(validate(Some(DAY_MS), None, Some(DAY_MS)).is_ok());
+        assert!(validate(None, Some(0), Some(DAY_MS)).is_ok());
+    }
+}
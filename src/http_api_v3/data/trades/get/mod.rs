@@ -0,0 +1,6 @@
+//!
+//! The recent trades GET.
+//!
+
+pub mod request;
+pub mod response;
@@ -0,0 +1,91 @@
+//!
+//! The recent trades GET request.
+//!
+
+use std::fmt;
+
+use crate::error::Error;
+
+///
+/// The `https://www.binance.com/api/v3/trades` GET request query.
+///
+#[derive(Debug)]
+pub struct Query {
+    /// The symbol name.
+    pub symbol: String,
+    /// The maximal number of trades. Default is 500, maximum is 1000.
+    pub limit: Option<usize>,
+}
+
+impl Query {
+    /// The query params default capacity.
+    const QUERY_INITIAL_CAPACITY: usize = 64;
+    /// The maximal number of trades per request.
+    pub const LIMIT_MAX: usize = 1000;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(symbol: &str, limit: Option<usize>) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            limit,
+        }
+    }
+
+    ///
+    /// Checks that the limit is at most `Query::LIMIT_MAX`, since Binance rejects the larger
+    /// values.
+    ///
+    pub fn validate(&self) -> Result<(), Error> {
+        match self.limit {
+            Some(limit) if limit > Self::LIMIT_MAX => Err(Error::InvalidRequest(format!(
+                "trades limit {} exceeds the maximum of {}",
+                limit,
+                Self::LIMIT_MAX
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    ///
+    /// The request weight.
+    ///
+    pub fn weight(&self) -> u32 {
+        25
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut params =
+            form_urlencoded::Serializer::new(String::with_capacity(Self::QUERY_INITIAL_CAPACITY));
+        params.append_pair("symbol", self.symbol.as_str());
+        if let Some(limit) = self.limit {
+            params.append_pair("limit", &limit.to_string());
+        }
+        write!(f, "{}", params.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+
+    use super::Query;
+
+    #[test]
+    fn limit_above_maximum_is_rejected() {
+        match Query::new("BTCUSDT", Some(Query::LIMIT_MAX + 1)).validate() {
+            Err(Error::InvalidRequest(_)) => {}
+            result => panic!("expected InvalidRequest, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn maximal_limit_passes() {
+        assert!(Query::new("BTCUSDT", Some(Query::LIMIT_MAX))
+            .validate()
+            .is_ok());
+    }
+}
@@ -0,0 +1,50 @@
+//!
+//! The recent trades GET response.
+//!
+
+use chrono::prelude::*;
+use serde::Deserialize;
+
+use crate::http_api_v3::data::serde::deserialize_price;
+use crate::http_api_v3::data::serde::PriceType;
+
+///
+/// The `https://www.binance.com/api/v3/trades` GET response.
+///
+pub type Response = Vec<Trade>;
+
+///
+/// A single market trade.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    /// The trade ID.
+    pub id: i64,
+    /// The trade price.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub price: PriceType,
+    /// The trade quantity in the secondary asset.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub qty: PriceType,
+    /// The trade quantity in the primary asset.
+    #[serde(deserialize_with = "deserialize_price")]
+    pub quote_qty: PriceType,
+    /// The trade time in milliseconds since Unix epoch.
+    pub time: i64,
+    /// If the buyer is the market maker.
+    pub is_buyer_maker: bool,
+    /// If the trade is the best price match.
+    pub is_best_match: bool,
+}
+
+impl Trade {
+    ///
+    /// The trade time as a date and time.
+    ///
+    pub fn datetime(&self) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(self.time)
+            .single()
+            .expect("Binance timestamps are within the date and time range")
+    }
+}
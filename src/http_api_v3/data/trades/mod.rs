@@ -0,0 +1,5 @@
+//!
+//! The recent trades.
+//!
+
+pub mod get;
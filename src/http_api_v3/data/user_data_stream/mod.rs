@@ -0,0 +1,5 @@
+//!
+//! The user data stream listen key.
+//!
+
+pub mod post;
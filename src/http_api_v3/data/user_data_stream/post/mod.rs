@@ -0,0 +1,5 @@
+//!
+//! The user data stream listen key POST.
+//!
+
+pub mod response;
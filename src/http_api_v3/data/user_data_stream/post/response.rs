@@ -0,0 +1,15 @@
+//!
+//! The user data stream listen key POST response.
+//!
+
+use serde::Deserialize;
+
+///
+/// The `https://www.binance.com/api/v3/userDataStream` POST response.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    /// The listen key, which is valid for 60 minutes unless kept alive.
+    pub listen_key: String,
+}
@@ -2,24 +2,60 @@
 //! The Binance API v3 HTTP client.
 //!
 
+pub mod authorization;
+pub mod builder;
 pub mod data;
+pub mod rate_limiter;
 pub mod response;
+pub mod retry_policy;
+pub mod signed_query;
+pub mod time_sync;
+pub mod transport;
+
+use std::collections::hash_map::RandomState;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::hash::Hasher;
+use std::sync::atomic::AtomicI64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::RwLock;
+use std::time::Duration;
+use std::time::Instant;
 
 use chrono::prelude::Utc;
-use hmac::Hmac;
-use hmac::Mac;
-use hmac::NewMac;
 use reqwest::Method;
 use reqwest::Url;
-use sha2::Sha256;
+use rust_decimal::Decimal;
 
 use self::data::account::get::request::Query as AccountGetQuery;
 use self::data::account::get::response::Response as AccountGetResponse;
+use self::data::account_commission::get::request::Query as AccountCommissionGetQuery;
+use self::data::account_commission::get::response::Response as AccountCommissionGetResponse;
+use self::data::agg_trades::get::request::Query as AggTradesGetQuery;
+use self::data::agg_trades::get::response::Response as AggTradesGetResponse;
+use self::data::all_orders::get::request::Query as AllOrdersGetQuery;
+use self::data::all_orders::get::response::Response as AllOrdersGetResponse;
+use self::data::book_ticker::get::request::Query as BookTickerGetQuery;
+use self::data::book_ticker::get::response::Response as BookTickerGetResponse;
 use self::data::depth::get::request::Query as DepthGetQuery;
 use self::data::depth::get::response::Response as DepthGetResponse;
 use self::data::exchange_info::get::response::Response as ExchangeInfoGetResponse;
+use self::data::historical_trades::get::request::Query as HistoricalTradesGetQuery;
+use self::data::historical_trades::get::response::Response as HistoricalTradesGetResponse;
+use self::data::interval::Interval;
 use self::data::klines::get::request::Query as KlinesGetQuery;
 use self::data::klines::get::response::Response as KlinesGetResponse;
+use self::data::my_prevented_matches::get::request::Query as MyPreventedMatchesGetQuery;
+use self::data::my_prevented_matches::get::response::Response as MyPreventedMatchesGetResponse;
+use self::data::my_trades::get::request::Query as MyTradesGetQuery;
+use self::data::my_trades::get::response::Response as MyTradesGetResponse;
+use self::data::open_order_list::get::request::Query as OpenOrderListGetQuery;
+use self::data::open_order_list::get::response::Response as OpenOrderListGetResponse;
 use self::data::open_orders::delete::request::Query as OpenOrdersDeleteQuery;
 use self::data::open_orders::delete::response::Response as OpenOrdersDeleteResponse;
 use self::data::open_orders::get::request::Query as OpenOrdersGetQuery;
@@ -29,28 +65,100 @@ use self::data::order::delete::response::Response as OrderDeleteResponse;
 use self::data::order::get::request::Query as OrderGetQuery;
 use self::data::order::get::response::Response as OrderGetResponse;
 use self::data::order::post::request::Query as OrderPostQuery;
+use self::data::order::post::response::idempotent::Idempotent as OrderPostIdempotentResponse;
 use self::data::order::post::response::Response as OrderPostResponse;
+use self::data::order::Order;
+use self::data::order_amend_keep_priority::put::request::Query as OrderAmendKeepPriorityPutQuery;
+use self::data::order_amend_keep_priority::put::response::Response as OrderAmendKeepPriorityPutResponse;
+use self::data::order_list::delete::request::Query as OrderListDeleteQuery;
+use self::data::order_list::delete::response::Response as OrderListDeleteResponse;
+use self::data::order_list::get::request::Query as OrderListGetQuery;
+use self::data::order_list::get::response::Response as OrderListGetResponse;
+use self::data::price_ticker::get::request::Query as PriceTickerGetQuery;
+use self::data::price_ticker::get::response::Response as PriceTickerGetResponse;
+use self::data::rate_limit_order::get::request::Query as RateLimitOrderGetQuery;
+use self::data::rate_limit_order::get::response::Response as RateLimitOrderGetResponse;
+use self::data::sapi::deposit_history::get::request::Query as DepositHistoryGetQuery;
+use self::data::sapi::deposit_history::get::response::Response as DepositHistoryGetResponse;
+use self::data::sapi::system_status::get::response::Response as SystemStatusGetResponse;
+use self::data::sapi::withdraw_history::get::request::Query as WithdrawHistoryGetQuery;
+use self::data::sapi::withdraw_history::get::response::Response as WithdrawHistoryGetResponse;
+use self::data::ticker_24hr::get::request::Query as Ticker24hrGetQuery;
+use self::data::ticker_24hr::get::response::Response as Ticker24hrGetResponse;
+use self::data::ticker_rolling::get::request::Query as TickerRollingGetQuery;
+use self::data::ticker_rolling::get::response::Response as TickerRollingGetResponse;
 use self::data::time::get::response::Response as TimeGetResponse;
+use self::data::trades::get::request::Query as TradesGetQuery;
+use self::data::trades::get::response::Response as TradesGetResponse;
+use self::data::user_data_stream::post::response::Response as UserDataStreamPostResponse;
 
 use crate::error::Error;
 
+use self::authorization::Authorization;
+use self::builder::Builder;
+use self::rate_limiter::RateLimiter;
+use self::response::Empty;
 use self::response::Response;
+use self::response::ResponseWithRaw;
+use self::retry_policy::RetryPolicy;
+use self::signed_query::SignedQuery;
+use self::time_sync::TimeSync;
+use self::transport::Transport;
+use self::transport::TransportRequest;
 
 ///
 /// The Binance API v3 HTTP client.
 ///
 #[derive(Debug, Clone)]
 pub struct Client {
-    /// The inner HTTP client.
-    inner: reqwest::Client,
+    /// The HTTP transport, which is shared between the client clones.
+    transport: Arc<dyn Transport>,
     /// The Binance authorization API key.
     api_key: Option<String>,
-    /// The Binance authorization secret key.
-    secret_key: Option<String>,
-    /// The request time offset.
-    timestamp_offset: i64,
+    /// The Binance request signing method.
+    authorization: Option<Authorization>,
+    /// The request time offset, which is shared between the client clones.
+    timestamp_offset: Arc<AtomicI64>,
+    /// The optional client-side rate limiter.
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// The interchangeable API base URLs, which are tried in turn on connection failures.
+    base_urls: Arc<Vec<String>>,
+    /// The index of the base URL, which is currently used. Shared between the client clones.
+    base_url_index: Arc<AtomicUsize>,
+    /// The request timestamp padding in milliseconds, which is added to the time offset.
+    timestamp_padding: i64,
+    /// Whether to check that the placed order client ID is the same as the requested one.
+    verify_client_order_id: bool,
+    /// Whether to check that the order symbol is trading before placing.
+    check_symbol_status: bool,
+    /// The symbol, which is set to the queries with an empty symbol.
+    default_symbol: Option<String>,
+    /// Whether to check that the placed limit orders satisfy the symbol notional filters.
+    check_notional: bool,
+    /// The default `recvWindow` of the signed requests, which do not set their own.
+    recv_window: Option<i64>,
+    /// Whether to resynchronize the time and retry once on the `-1021` error.
+    resync_on_timestamp_error: bool,
+    /// The number of the consecutive `-1021` or `-1022` errors, after which the clock drift is
+    /// reported.
+    clock_drift_threshold: usize,
+    /// The number of the consecutive `-1021` or `-1022` errors, which is shared between the
+    /// client clones.
+    timestamp_failures: Arc<AtomicUsize>,
+    /// The retry policy of the failed requests.
+    retry_policy: RetryPolicy,
+    /// The time the cached exchange info is considered fresh.
+    exchange_info_ttl: Duration,
+    /// The cached exchange info with its fetch time, which is shared between the client clones.
+    exchange_info_cache: Arc<RwLock<ExchangeInfoCache>>,
 }
 
+/// Ensures the client can be shared between threads, e.g. via `Arc<Client>`.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Client>();
+};
+
 impl Default for Client {
     fn default() -> Self {
         Self::new()
@@ -59,320 +167,1793 @@ impl Default for Client {
 
 type Result<T> = ::std::result::Result<T, Error>;
 
+/// The cached exchange info with its fetch time.
+type ExchangeInfoCache = Option<(Instant, Arc<ExchangeInfoGetResponse>)>;
+
 impl Client {
-    /// The API base URL.
-    const BASE_URL: &'static str = "https://api.binance.com";
-    /// The request timestamp offset, which is substituted from the request time to prevent
-    /// the `request window missed` error.
-    const REQUEST_TIMESTAMP_OFFSET: i64 = 1000;
+    /// The default API base URLs.
+    pub const BASE_URLS: &'static [&'static str] = &[
+        "https://api.binance.com",
+        "https://api-gcp.binance.com",
+        "https://api1.binance.com",
+        "https://api2.binance.com",
+        "https://api3.binance.com",
+        "https://api4.binance.com",
+    ];
+    /// The maximal length of the non-JSON response body excerpt.
+    const RESPONSE_EXCERPT_LENGTH: usize = 256;
+    /// The `Invalid API-key, IP, or permissions for action` Binance error code.
+    const INVALID_API_KEY: i64 = -2015;
+    /// The `Timestamp for this request is outside of the recvWindow` Binance error code.
+    const TIMESTAMP_OUTSIDE_RECV_WINDOW: i64 = -1021;
+    /// The `Signature for this request is not valid` Binance error code.
+    const INVALID_SIGNATURE: i64 = -1022;
+    /// The `Unknown order sent` Binance error code, which is returned on cancelling an order,
+    /// which is already inactive, or the open orders, if there are none.
+    const UNKNOWN_ORDER: i64 = -2011;
+    /// The open orders polling interval, while waiting for the cancellation confirmation.
+    const CANCEL_CONFIRMATION_INTERVAL: Duration = Duration::from_millis(250);
+    /// The default request timestamp padding in milliseconds, which is subtracted from the
+    /// request time to prevent the `Timestamp for this request was 1000ms ahead of the server's
+    /// time` error, if the system clock is ahead of Binance or the time offset is inaccurate.
+    pub const REQUEST_TIMESTAMP_OFFSET: i64 = 1000;
+    /// The environment variable with the API key, which is read by `from_env`.
+    pub const API_KEY_ENV: &'static str = "BINANCE_API_KEY";
+    /// The environment variable with the HMAC secret key, which is read by `from_env`.
+    pub const SECRET_KEY_ENV: &'static str = "BINANCE_SECRET_KEY";
+    /// The default time the cached exchange info is considered fresh.
+    pub const EXCHANGE_INFO_TTL_DEFAULT: Duration = Duration::from_secs(3600);
+    /// The default number of the consecutive `-1021` or `-1022` errors, after which the clock
+    /// drift is reported.
+    pub const CLOCK_DRIFT_THRESHOLD_DEFAULT: usize = 3;
+    /// The maximal `recvWindow` in milliseconds, which Binance accepts.
+    pub const RECV_WINDOW_MAX: i64 = 60_000;
+    /// The default `User-Agent` header value.
+    pub const USER_AGENT: &'static str = concat!("binance-client/", env!("CARGO_PKG_VERSION"));
 
     ///
     /// Creates an unauthorized client instance.
     ///
     pub fn new() -> Self {
-        let mut client = Self {
-            inner: reqwest::Client::new(),
-            api_key: None,
-            secret_key: None,
-            timestamp_offset: 0,
-        };
-
-        client.timestamp_offset = client.timestamp_offset();
-        client
+        Builder::new().build().expect("Time request")
     }
 
     ///
-    /// Creates an authorized client instance.
+    /// Creates an authorized client instance, which signs requests with HMAC-SHA256.
     ///
     pub fn new_with_auth(api_key: String, secret_key: String) -> Self {
-        let mut client = Self {
-            inner: reqwest::Client::new(),
-            api_key: Some(api_key),
-            secret_key: Some(secret_key),
-            timestamp_offset: 0,
-        };
+        Builder::new()
+            .api_key(api_key)
+            .secret_key(secret_key)
+            .build()
+            .expect("Time request")
+    }
+
+    ///
+    /// Creates an authorized client instance, which signs requests with HMAC-SHA256, with the keys
+    /// read from the `BINANCE_API_KEY` and `BINANCE_SECRET_KEY` environment variables.
+    ///
+    /// Fails with `MissingEnvVar` if any of the variables is not set.
+    ///
+    pub fn from_env() -> Result<Self> {
+        let var =
+            |name: &str| std::env::var(name).map_err(|_| Error::MissingEnvVar(name.to_owned()));
+
+        Builder::new()
+            .api_key(var(Self::API_KEY_ENV)?)
+            .secret_key(var(Self::SECRET_KEY_ENV)?)
+            .build()
+    }
+
+    ///
+    /// Creates a client builder, which allows to choose the request signing method.
+    ///
+    pub fn builder() -> Builder {
+        Builder::new()
+    }
+
+    ///
+    /// Recalculates the request time offset between the system time and Binance time.
+    ///
+    /// The offset is shared between the client clones, so it is enough to call it once.
+    ///
+    pub fn synchronize_time(&self) -> Result<()> {
+        let timestamp_offset = self.calculate_timestamp_offset()?;
+        self.timestamp_offset
+            .store(timestamp_offset, Ordering::Relaxed);
+        Ok(())
+    }
+
+    ///
+    /// Measures the clock skew between the system and Binance with a time request.
+    ///
+    /// Unlike `synchronize_time`, does not update the request time offset, so it may be called
+    /// periodically to monitor the skew, e.g. to alert before the `-1021` errors start.
+    ///
+    pub fn measure_time_sync(&self) -> Result<TimeSync> {
+        let system_ms = Utc::now().timestamp_millis();
+        let request_time = Instant::now();
+        let server_time = self.time()?.server_time;
+        let round_trip_ms = request_time.elapsed().as_millis() as i64;
+        let server_ms = server_time - round_trip_ms / 2;
+
+        Ok(TimeSync {
+            system_ms,
+            server_ms,
+            offset_ms: system_ms - server_ms,
+            round_trip_ms,
+        })
+    }
 
-        client.timestamp_offset = client.timestamp_offset();
-        client
+    ///
+    /// The current request time offset, which is subtracted from the signed query timestamps.
+    ///
+    /// Equals the clock skew measured on the last synchronization plus the timestamp padding.
+    ///
+    pub fn time_offset(&self) -> i64 {
+        self.timestamp_offset.load(Ordering::Relaxed)
     }
 
     ///
     /// Test connectivity to the Rest API.
     ///
     pub fn ping(&self) -> Result<()> {
-        self.execute::<()>(Method::GET, "/api/v3/ping".to_owned())
+        self.execute::<Empty>(Method::GET, "/api/v3/ping".to_owned(), 1)
+            .map(|_| ())
+    }
+
+    ///
+    /// Test connectivity to the Rest API and measure the request round-trip time.
+    ///
+    pub fn ping_timed(&self) -> Result<Duration> {
+        let started_at = Instant::now();
+        self.ping()?;
+        Ok(started_at.elapsed())
     }
 
     ///
     /// Test connectivity to the Rest API and get the current server time.
     ///
     pub fn time(&self) -> Result<TimeGetResponse> {
-        self.execute::<TimeGetResponse>(Method::GET, "/api/v3/time".to_owned())
+        self.execute::<TimeGetResponse>(Method::GET, "/api/v3/time".to_owned(), 1)
     }
 
     ///
     /// Current exchange trading rules and symbol information.
     ///
     pub fn exchange_info(&self) -> Result<ExchangeInfoGetResponse> {
-        self.execute::<ExchangeInfoGetResponse>(Method::GET, "/api/v3/exchangeInfo".to_owned())
+        self.execute::<ExchangeInfoGetResponse>(Method::GET, "/api/v3/exchangeInfo".to_owned(), 20)
+    }
+
+    ///
+    /// The cached exchange info, which is only requested again after the TTL set in the builder
+    /// expires, e.g. to check the symbol filters before each order.
+    ///
+    /// The cache is shared between the client clones.
+    ///
+    pub fn exchange_info_cached(&self) -> Result<Arc<ExchangeInfoGetResponse>> {
+        let is_fresh = |cache: &ExchangeInfoCache| match cache {
+            Some((fetched_at, exchange_info)) if fetched_at.elapsed() < self.exchange_info_ttl => {
+                Some(exchange_info.clone())
+            }
+            _ => None,
+        };
+
+        if let Some(exchange_info) = is_fresh(
+            &self
+                .exchange_info_cache
+                .read()
+                .expect("Exchange info cache lock"),
+        ) {
+            return Ok(exchange_info);
+        }
+
+        let mut cache = self
+            .exchange_info_cache
+            .write()
+            .expect("Exchange info cache lock");
+        if let Some(exchange_info) = is_fresh(&cache) {
+            return Ok(exchange_info);
+        }
+        let exchange_info = Arc::new(self.exchange_info()?);
+        *cache = Some((Instant::now(), exchange_info.clone()));
+        Ok(exchange_info)
     }
 
     ///
     /// Kline/candlestick bars for a symbol.
     /// Klines are uniquely identified by their open time.
     ///
-    pub fn klines(&self, request: KlinesGetQuery) -> Result<KlinesGetResponse> {
+    pub fn klines(&self, mut request: KlinesGetQuery) -> Result<KlinesGetResponse> {
+        self.fill_default_symbol(&mut request.symbol);
+        request.validate()?;
+
         self.execute::<KlinesGetResponse>(
             Method::GET,
             format!("/api/v3/klines?{}", request.to_string()),
+            request.weight(),
+        )
+    }
+
+    ///
+    /// The latest `limit` klines of `symbol` on each of the `intervals`, requested concurrently.
+    ///
+    /// The requests share the client rate limiter, if enabled. Fails with the first error, after
+    /// all the requests are finished.
+    ///
+    pub fn klines_multi(
+        &self,
+        symbol: &str,
+        intervals: Vec<Interval>,
+        limit: Option<usize>,
+    ) -> Result<HashMap<Interval, KlinesGetResponse>> {
+        let workers: Vec<(Interval, std::thread::JoinHandle<Result<KlinesGetResponse>>)> =
+            intervals
+                .into_iter()
+                .map(|interval| {
+                    let client = self.clone();
+                    let request =
+                        KlinesGetQuery::new(symbol.to_owned(), interval, None, None, limit);
+                    (interval, std::thread::spawn(move || client.klines(request)))
+                })
+                .collect();
+
+        let mut klines = HashMap::with_capacity(workers.len());
+        let mut error = None;
+        for (interval, worker) in workers.into_iter() {
+            match worker.join().expect("Klines worker panicked") {
+                Ok(interval_klines) => {
+                    klines.insert(interval, interval_klines);
+                }
+                Err(interval_error) => {
+                    error.get_or_insert(interval_error);
+                }
+            }
+        }
+
+        match error {
+            Some(error) => Err(error),
+            None => Ok(klines),
+        }
+    }
+
+    ///
+    /// Kline/candlestick bars for a symbol, modified for the presentation of candlestick charts.
+    /// The request and response are the same as the ones of `klines`.
+    ///
+    pub fn ui_klines(&self, mut request: KlinesGetQuery) -> Result<KlinesGetResponse> {
+        self.fill_default_symbol(&mut request.symbol);
+        request.validate()?;
+
+        self.execute::<KlinesGetResponse>(
+            Method::GET,
+            format!("/api/v3/uiKlines?{}", request.to_string()),
+            request.weight(),
         )
     }
 
     ///
     /// The real-time market depth.
     ///
-    pub fn depth(&self, request: DepthGetQuery) -> Result<DepthGetResponse> {
+    /// Fails with `InvalidRequest` without sending the request, if the limit is not supported.
+    ///
+    pub fn depth(&self, mut request: DepthGetQuery) -> Result<DepthGetResponse> {
+        self.fill_default_symbol(&mut request.symbol);
+        request.validate()?;
+
         self.execute::<DepthGetResponse>(
             Method::GET,
             format!("/api/v3/depth?{}", request.to_string()),
+            request.weight(),
         )
     }
 
     ///
-    /// Get the account info and balances.
+    /// The 24 hour rolling window price change statistics of one, several, or all symbols.
     ///
-    pub fn account_get(&self, mut request: AccountGetQuery) -> Result<AccountGetResponse> {
-        let secret_key = self
-            .secret_key
-            .as_ref()
-            .ok_or(Error::AuthorizationKeysMissing)?;
+    pub fn ticker_24hr(&self, request: Ticker24hrGetQuery) -> Result<Ticker24hrGetResponse> {
+        self.execute::<Ticker24hrGetResponse>(
+            Method::GET,
+            format!("/api/v3/ticker/24hr?{}", request),
+            request.weight(),
+        )
+    }
+
+    ///
+    /// The price change statistics of one or several symbols within a configurable rolling window.
+    ///
+    pub fn ticker_rolling(
+        &self,
+        request: TickerRollingGetQuery,
+    ) -> Result<TickerRollingGetResponse> {
+        self.execute::<TickerRollingGetResponse>(
+            Method::GET,
+            format!("/api/v3/ticker?{}", request),
+            request.weight(),
+        )
+    }
 
-        request.timestamp -= self.timestamp_offset;
+    ///
+    /// The best price and quantity on the order book of one, several, or all symbols.
+    ///
+    pub fn book_ticker(&self, request: BookTickerGetQuery) -> Result<BookTickerGetResponse> {
+        self.execute::<BookTickerGetResponse>(
+            Method::GET,
+            format!("/api/v3/ticker/bookTicker?{}", request),
+            request.weight(),
+        )
+    }
 
-        let mut params = request.to_string();
-        params += &format!("&signature={}", Self::signature(&params, secret_key));
+    ///
+    /// The recent trades.
+    ///
+    pub fn trades(&self, mut request: TradesGetQuery) -> Result<TradesGetResponse> {
+        self.fill_default_symbol(&mut request.symbol);
+        request.validate()?;
 
-        self.execute_signed::<AccountGetResponse>(
+        self.execute::<TradesGetResponse>(
             Method::GET,
-            format!("/api/v3/account?{}", params),
+            format!("/api/v3/trades?{}", request),
+            request.weight(),
         )
     }
 
     ///
-    /// Get the account open orders.
+    /// The older trades, which are paginated by the trade ID.
     ///
-    pub fn open_orders_get(
+    /// The request requires the API key, but is not signed.
+    ///
+    pub fn historical_trades(
         &self,
-        mut request: OpenOrdersGetQuery,
-    ) -> Result<OpenOrdersGetResponse> {
-        let secret_key = self
-            .secret_key
-            .as_ref()
-            .ok_or(Error::AuthorizationKeysMissing)?;
+        mut request: HistoricalTradesGetQuery,
+    ) -> Result<HistoricalTradesGetResponse> {
+        self.fill_default_symbol(&mut request.symbol);
+        request.validate()?;
 
-        request.timestamp -= self.timestamp_offset;
+        self.execute_keyed::<HistoricalTradesGetResponse>(
+            Method::GET,
+            format!("/api/v3/historicalTrades?{}", request),
+            request.weight(),
+        )
+    }
 
-        let mut params = request.to_string();
-        params += &format!("&signature={}", Self::signature(&params, secret_key));
+    ///
+    /// The compressed, aggregate trades, which are filled at the same time, from the same taker
+    /// order, at the same price.
+    ///
+    pub fn agg_trades(&self, mut request: AggTradesGetQuery) -> Result<AggTradesGetResponse> {
+        self.fill_default_symbol(&mut request.symbol);
+        request.validate()?;
 
-        self.execute_signed::<OpenOrdersGetResponse>(
+        self.execute::<AggTradesGetResponse>(
             Method::GET,
-            format!("/api/v3/openOrders?{}", params),
+            format!("/api/v3/aggTrades?{}", request),
+            request.weight(),
         )
     }
 
     ///
-    /// Delete the account open orders.
+    /// The latest price of one, several, or all symbols.
     ///
-    pub fn open_orders_delete(
-        &self,
-        mut request: OpenOrdersDeleteQuery,
-    ) -> Result<OpenOrdersDeleteResponse> {
-        let secret_key = self
-            .secret_key
-            .as_ref()
-            .ok_or(Error::AuthorizationKeysMissing)?;
+    pub fn price_ticker(&self, request: PriceTickerGetQuery) -> Result<PriceTickerGetResponse> {
+        self.execute::<PriceTickerGetResponse>(
+            Method::GET,
+            format!("/api/v3/ticker/price?{}", request),
+            request.weight(),
+        )
+    }
 
-        request.timestamp -= self.timestamp_offset;
+    ///
+    /// The latest price of the `symbol`, or of all symbols if it is `None`.
+    ///
+    pub fn price_ticker_by_symbol(&self, symbol: Option<String>) -> Result<PriceTickerGetResponse> {
+        let request = match symbol {
+            Some(symbol) => PriceTickerGetQuery::new(symbol.as_str()),
+            None => PriceTickerGetQuery::all(),
+        };
+        self.price_ticker(request)
+    }
 
-        let mut params = request.to_string();
-        params += &format!("&signature={}", Self::signature(&params, secret_key));
+    ///
+    /// Get the account info and balances.
+    ///
+    pub fn account_get(&self, mut request: AccountGetQuery) -> Result<AccountGetResponse> {
+        let params = self.stamp_and_sign(&mut request)?;
 
-        self.execute_signed::<OpenOrdersDeleteResponse>(
-            Method::DELETE,
-            format!("/api/v3/openOrders?{}", params),
+        self.execute_signed::<AccountGetResponse>(
+            Method::GET,
+            format!("/api/v3/account?{}", params),
+            request.weight(),
+            0,
         )
     }
 
     ///
-    /// Check an order's status.
+    /// Get the account commission rates on the symbol.
     ///
-    pub fn order_get(&self, mut request: OrderGetQuery) -> Result<OrderGetResponse> {
-        let secret_key = self
-            .secret_key
-            .as_ref()
-            .ok_or(Error::AuthorizationKeysMissing)?;
+    pub fn account_commission(
+        &self,
+        mut request: AccountCommissionGetQuery,
+    ) -> Result<AccountCommissionGetResponse> {
+        self.fill_default_symbol(&mut request.symbol);
+        let params = self.stamp_and_sign(&mut request)?;
 
-        request.timestamp -= self.timestamp_offset;
+        self.execute_signed::<AccountCommissionGetResponse>(
+            Method::GET,
+            format!("/api/v3/account/commission?{}", params),
+            request.weight(),
+            0,
+        )
+    }
 
-        let mut params = request.to_string();
-        params += &format!("&signature={}", Self::signature(&params, secret_key));
+    ///
+    /// Get the current order count usage within all the order rate limit windows.
+    ///
+    pub fn rate_limit_order(
+        &self,
+        mut request: RateLimitOrderGetQuery,
+    ) -> Result<RateLimitOrderGetResponse> {
+        let params = self.stamp_and_sign(&mut request)?;
 
-        self.execute_signed::<OrderGetResponse>(Method::GET, format!("/api/v3/order?{}", params))
+        self.execute_signed::<RateLimitOrderGetResponse>(
+            Method::GET,
+            format!("/api/v3/rateLimit/order?{}", params),
+            request.weight(),
+            0,
+        )
     }
 
     ///
-    /// Send in a new order.
+    /// Get the account open orders.
     ///
-    pub fn order_post(&self, mut request: OrderPostQuery) -> Result<OrderPostResponse> {
-        let secret_key = self
-            .secret_key
-            .as_ref()
-            .ok_or(Error::AuthorizationKeysMissing)?;
+    pub fn open_orders_get(
+        &self,
+        mut request: OpenOrdersGetQuery,
+    ) -> Result<OpenOrdersGetResponse> {
+        let params = self.stamp_and_sign(&mut request)?;
 
-        request.timestamp -= self.timestamp_offset;
+        self.execute_signed::<OpenOrdersGetResponse>(
+            Method::GET,
+            format!("/api/v3/openOrders?{}", params),
+            request.weight(),
+            0,
+        )
+    }
 
-        let mut params = request.to_string();
-        params += &format!("&signature={}", Self::signature(&params, secret_key));
+    ///
+    /// Get the account open orders grouped by the symbol name.
+    ///
+    pub fn open_orders_by_symbol(
+        &self,
+        request: OpenOrdersGetQuery,
+    ) -> Result<HashMap<String, Vec<Order>>> {
+        let mut orders: HashMap<String, Vec<Order>> = HashMap::new();
+        for order in self.open_orders_get(request)?.into_iter() {
+            orders.entry(order.symbol.clone()).or_default().push(order);
+        }
+        Ok(orders)
+    }
 
-        self.execute_signed::<OrderPostResponse>(Method::POST, format!("/api/v3/order?{}", params))
+    ///
+    /// The total notional of the account open orders on the symbol in the quote asset, that is,
+    /// the sum of the price multiplied by the remaining quantity of each order.
+    ///
+    pub fn open_exposure(&self, symbol: &str) -> Result<Decimal> {
+        Ok(self
+            .open_orders_get(OpenOrdersGetQuery::new(Some(symbol.to_owned())))?
+            .iter()
+            .map(|order| order.price * order.remaining_qty())
+            .fold(Decimal::new(0, 0), |total, notional| total + notional))
     }
 
     ///
-    /// Cancel an active order.
+    /// Get the account orders matches, which have been prevented by the self-trade prevention.
     ///
-    pub fn order_delete(&self, mut request: OrderDeleteQuery) -> Result<OrderDeleteResponse> {
-        let secret_key = self
-            .secret_key
-            .as_ref()
-            .ok_or(Error::AuthorizationKeysMissing)?;
+    pub fn my_prevented_matches(
+        &self,
+        mut request: MyPreventedMatchesGetQuery,
+    ) -> Result<MyPreventedMatchesGetResponse> {
+        self.fill_default_symbol(&mut request.symbol);
+        let params = self.stamp_and_sign(&mut request)?;
 
-        request.timestamp -= self.timestamp_offset;
+        self.execute_signed::<MyPreventedMatchesGetResponse>(
+            Method::GET,
+            format!("/api/v3/myPreventedMatches?{}", params),
+            request.weight(),
+            0,
+        )
+    }
 
-        let mut params = request.to_string();
-        params += &format!("&signature={}", Self::signature(&params, secret_key));
+    ///
+    /// Get the account trades on the symbol.
+    ///
+    pub fn my_trades_get(&self, mut request: MyTradesGetQuery) -> Result<MyTradesGetResponse> {
+        self.fill_default_symbol(&mut request.symbol);
+        request.validate()?;
+        let params = self.stamp_and_sign(&mut request)?;
 
-        self.execute_signed::<OrderDeleteResponse>(
-            Method::DELETE,
-            format!("/api/v3/order?{}", params),
+        self.execute_signed::<MyTradesGetResponse>(
+            Method::GET,
+            format!("/api/v3/myTrades?{}", params),
+            request.weight(),
+            0,
         )
     }
 
     ///
-    /// Test new order creation and signature/recvWindow long.
-    /// Creates and validates a new order but does not send it into the matching engine.
+    /// The volume-weighted average execution price of the order, including all its partial fills.
     ///
-    pub fn order_post_test(&self, mut request: OrderPostQuery) -> Result<OrderPostResponse> {
-        let secret_key = self
-            .secret_key
-            .as_ref()
-            .ok_or(Error::AuthorizationKeysMissing)?;
+    /// `None` if the order has no trades. The trades are requested by pages of
+    /// `MyTradesGetQuery::LIMIT_MAX` until a shorter page is returned.
+    ///
+    pub fn order_avg_price(&self, symbol: &str, order_id: i64) -> Result<Option<Decimal>> {
+        let mut notional = Decimal::new(0, 0);
+        let mut quantity = Decimal::new(0, 0);
+        let mut from_id = None;
+        loop {
+            let mut request = MyTradesGetQuery::new_by_order(symbol, order_id);
+            request.from_id = from_id;
+            request.limit = Some(MyTradesGetQuery::LIMIT_MAX);
 
-        request.timestamp -= self.timestamp_offset;
+            let trades = self.my_trades_get(request)?;
+            for trade in trades.iter() {
+                notional += trade.price * trade.qty;
+                quantity += trade.qty;
+            }
+            match trades.last() {
+                Some(last) if trades.len() >= MyTradesGetQuery::LIMIT_MAX => {
+                    from_id = Some(last.id + 1)
+                }
+                _ => break,
+            }
+        }
 
-        let mut params = request.to_string();
-        params += &format!("&signature={}", Self::signature(&params, secret_key));
+        if quantity == Decimal::new(0, 0) {
+            return Ok(None);
+        }
+        Ok(Some(notional / quantity))
+    }
 
-        self.execute_signed::<OrderPostResponse>(
-            Method::POST,
-            format!("/api/v3/order/test?{}", params),
+    ///
+    /// Get the account orders on the symbol, including the inactive ones.
+    ///
+    pub fn all_orders_get(&self, mut request: AllOrdersGetQuery) -> Result<AllOrdersGetResponse> {
+        self.fill_default_symbol(&mut request.symbol);
+        request.validate()?;
+        let params = self.stamp_and_sign(&mut request)?;
+
+        self.execute_signed::<AllOrdersGetResponse>(
+            Method::GET,
+            format!("/api/v3/allOrders?{}", params),
+            request.weight(),
+            0,
         )
     }
 
     ///
-    /// Executes an unauthorized request.
+    /// Get the account orders on each of the `symbols`, including the inactive ones, with at most
+    /// `concurrency` requests in flight.
     ///
-    fn execute<T>(&self, method: Method, url: String) -> Result<T>
-    where
-        T: serde::de::DeserializeOwned,
-    {
-        let url = Self::BASE_URL.to_owned() + url.as_str();
-
-        let response = self
-            .inner
-            .execute(
-                self.inner
-                    .request(
-                        method,
-                        Url::parse(&url).map_err(|error| Error::UrlParsing(error, url))?,
-                    )
-                    .build()
-                    .map_err(Error::RequestBuilding)?,
-            )
-            .map_err(Error::RequestExecution)?
-            .text()
-            .map_err(Error::ResponseReading)?;
-        let response: Response<T> = serde_json::from_str(response.as_str())
-            .map_err(|error| Error::ResponseParsing(error, response))?;
+    /// The requests share the client rate limiter, if enabled. Fails with the first error, after
+    /// the requests in flight are finished.
+    ///
+    pub fn all_orders_multi(
+        &self,
+        symbols: Vec<String>,
+        concurrency: usize,
+    ) -> Result<HashMap<String, Vec<Order>>> {
+        let count = symbols.len();
+        let queue = Arc::new(Mutex::new(symbols));
+        let (tx, rx) = mpsc::channel();
 
-        match response {
-            Response::Ok(response) => Ok(response),
-            Response::Error(error) => Err(Error::ResponseError(error)),
+        let workers: Vec<std::thread::JoinHandle<()>> = (0..concurrency.max(1).min(count))
+            .map(|_| {
+                let client = self.clone();
+                let queue = queue.clone();
+                let tx = tx.clone();
+                std::thread::spawn(move || loop {
+                    let symbol = match queue.lock().expect("Symbol queue lock").pop() {
+                        Some(symbol) => symbol,
+                        None => return,
+                    };
+                    let result = client.all_orders_get(AllOrdersGetQuery::new(symbol.as_str()));
+                    let is_failed = result.is_err();
+                    if tx.send((symbol, result)).is_err() || is_failed {
+                        return;
+                    }
+                })
+            })
+            .collect();
+        drop(tx);
+
+        let mut orders = HashMap::with_capacity(count);
+        let mut error = None;
+        for (symbol, result) in rx.iter() {
+            match result {
+                Ok(symbol_orders) => {
+                    orders.insert(symbol, symbol_orders);
+                }
+                Err(symbol_error) => {
+                    queue.lock().expect("Symbol queue lock").clear();
+                    error.get_or_insert(symbol_error);
+                }
+            }
+        }
+        for worker in workers.into_iter() {
+            worker.join().expect("All orders worker panicked");
+        }
+
+        match error {
+            Some(error) => Err(error),
+            None => Ok(orders),
         }
     }
 
     ///
-    /// Executes an authorized request.
+    /// Delete the account open orders.
     ///
-    fn execute_signed<T>(&self, method: Method, url: String) -> Result<T>
-    where
-        T: serde::de::DeserializeOwned,
-    {
-        let api_key = self
-            .api_key
-            .as_ref()
-            .ok_or(Error::AuthorizationKeysMissing)?;
+    /// Returns a report for each order, including the ones which could not be cancelled.
+    ///
+    pub fn open_orders_delete(
+        &self,
+        mut request: OpenOrdersDeleteQuery,
+    ) -> Result<OpenOrdersDeleteResponse> {
+        self.fill_default_symbol(&mut request.symbol);
+        let params = self.stamp_and_sign(&mut request)?;
 
-        let url = Self::BASE_URL.to_owned() + url.as_str();
-
-        let response = self
-            .inner
-            .execute(
-                self.inner
-                    .request(
-                        method,
-                        Url::parse(&url).map_err(|error| Error::UrlParsing(error, url))?,
-                    )
-                    .header("X-MBX-APIKEY", api_key.to_owned())
-                    .build()
-                    .map_err(Error::RequestBuilding)?,
-            )
-            .map_err(Error::RequestExecution)?
-            .text()
-            .map_err(Error::ResponseReading)?;
-        let response: Response<T> = serde_json::from_str(response.as_str())
-            .map_err(|error| Error::ResponseParsing(error, response))?;
+        self.execute_signed::<OpenOrdersDeleteResponse>(
+            Method::DELETE,
+            format!("/api/v3/openOrders?{}", params),
+            request.weight(),
+            0,
+        )
+    }
 
-        match response {
-            Response::Ok(response) => Ok(response),
-            Response::Error(error) => Err(Error::ResponseError(error)),
+    ///
+    /// Cancel all the open orders on the symbol and wait until none of them is reported as open,
+    /// polling the open orders every 250 milliseconds.
+    ///
+    /// Fails with `CancelConfirmationTimeout` if some orders are still open after `timeout`.
+    ///
+    pub fn cancel_all_and_confirm(&self, symbol: &str, timeout: Duration) -> Result<()> {
+        let started_at = Instant::now();
+        match self.open_orders_delete(OpenOrdersDeleteQuery::new(symbol.to_owned())) {
+            Ok(_) => {}
+            Err(Error::ResponseError(ref error)) if error.code == Self::UNKNOWN_ORDER => {}
+            Err(error) => return Err(error),
+        }
+
+        loop {
+            let open_orders =
+                self.open_orders_get(OpenOrdersGetQuery::new(Some(symbol.to_owned())))?;
+            if open_orders.is_empty() {
+                return Ok(());
+            }
+            if started_at.elapsed() >= timeout {
+                return Err(Error::CancelConfirmationTimeout(
+                    symbol.to_owned(),
+                    open_orders.len(),
+                ));
+            }
+            std::thread::sleep(Self::CANCEL_CONFIRMATION_INTERVAL);
         }
     }
 
     ///
-    /// Generates an HMAC signature for authorized requests.
+    /// Check an order's status.
     ///
-    fn signature(params: &str, secret_key: &str) -> String {
-        hex::encode(
-            {
-                let mut hmac: Hmac<Sha256> =
-                    Hmac::new_varkey(secret_key.as_bytes()).expect("HMAC is valid");
-                hmac.update(params.as_bytes());
-                hmac.finalize().into_bytes()
-            }
-            .to_vec(),
+    pub fn order_get(&self, mut request: OrderGetQuery) -> Result<OrderGetResponse> {
+        self.fill_default_symbol(&mut request.symbol);
+        let params = self.stamp_and_sign(&mut request)?;
+
+        self.execute_signed::<OrderGetResponse>(
+            Method::GET,
+            format!("/api/v3/order?{}", params),
+            request.weight(),
+            0,
         )
     }
 
     ///
-    /// Calculates the request timestamp offsets between the system time and Binance time.
+    /// Send in a new order.
     ///
-    fn timestamp_offset(&self) -> i64 {
-        let system_time = Utc::now().timestamp_millis();
-        let request_time = std::time::Instant::now();
-        let binance_time = self.time().expect("Time request").server_time
-            - (request_time.elapsed().as_millis() as i64) / 2;
+    /// Fails with `InvalidRequest` without sending the request, if the trailing delta is set on
+    /// an order type which does not support it.
+    ///
+    pub fn order_post(&self, mut request: OrderPostQuery) -> Result<OrderPostResponse> {
+        self.fill_default_symbol(&mut request.symbol);
+        request.validate()?;
+        self.check_symbol_status(request.symbol.as_str())?;
+
+        if let (true, Some(price), Some(quantity)) =
+            (self.check_notional, request.price, request.quantity)
+        {
+            if let Some(symbol) = self.exchange_info_cached()?.symbol(request.symbol.as_str()) {
+                symbol.check_notional(price, quantity)?;
+            }
+        }
+
+        let params = self.stamp_and_sign(&mut request)?;
+
+        let response = self.execute_signed::<OrderPostResponse>(
+            Method::POST,
+            format!("/api/v3/order?{}", params),
+            request.weight(),
+            1,
+        )?;
+
+        if let (true, Some(sent)) = (self.verify_client_order_id, request.new_client_order_id) {
+            let received = response.client_order_id();
+            if sent != received {
+                return Err(Error::ClientOrderIdMismatch(sent, received));
+            }
+        }
+        Ok(response)
+    }
 
-        (system_time - binance_time) + Self::REQUEST_TIMESTAMP_OFFSET
+    ///
+    /// Send in a new order, which is safe to retry after network failures.
+    ///
+    /// The client order ID is generated, unless `new_client_order_id` is set. If the request fails
+    /// after it might have reached Binance, the order is looked up by the client order ID and is
+    /// only sent again if it does not exist.
+    ///
+    /// Set `new_client_order_id` explicitly to be able to repeat the call itself safely, e.g. if
+    /// the lookup has failed too.
+    ///
+    pub fn order_post_idempotent(
+        &self,
+        mut request: OrderPostQuery,
+    ) -> Result<OrderPostIdempotentResponse> {
+        /// The `Order does not exist` Binance error code.
+        const ORDER_DOES_NOT_EXIST: i64 = -2013;
+
+        let client_order_id = request
+            .new_client_order_id
+            .get_or_insert_with(Self::generate_client_order_id)
+            .to_owned();
+        let retry = request.clone();
+
+        let error = match self.order_post(request) {
+            Ok(response) => return Ok(OrderPostIdempotentResponse::Placed(response)),
+            Err(error @ Error::RequestExecution(_)) => error,
+            Err(error @ Error::Connection(_)) => error,
+            Err(error @ Error::ResponseReading(_)) => error,
+            Err(error @ Error::ServerError(..)) => error,
+            Err(error) => return Err(error),
+        };
+        log::warn!(
+            "Order {} placement failed: {}, looking it up",
+            client_order_id,
+            error
+        );
+
+        let mut lookup = OrderGetQuery::new(retry.symbol.as_str(), client_order_id.as_str());
+        lookup.recv_window = retry.recv_window;
+        match self.order_get(lookup) {
+            Ok(order) => Ok(OrderPostIdempotentResponse::Existing(order)),
+            Err(Error::ResponseError(ref error)) if error.code == ORDER_DOES_NOT_EXIST => self
+                .order_post(retry)
+                .map(OrderPostIdempotentResponse::Placed),
+            Err(error) => Err(error),
+        }
+    }
+
+    ///
+    /// Send in a new order using the Smart Order Routing, which may fill it in the order books
+    /// of the symbols with interchangeable quote assets.
+    ///
+    /// Only the `LIMIT` and `MARKET` orders with `quantity` are supported.
+    ///
+    pub fn sor_order_post(&self, mut request: OrderPostQuery) -> Result<OrderPostResponse> {
+        self.fill_default_symbol(&mut request.symbol);
+        request.validate()?;
+        self.check_symbol_status(request.symbol.as_str())?;
+
+        let params = self.stamp_and_sign(&mut request)?;
+
+        self.execute_signed::<OrderPostResponse>(
+            Method::POST,
+            format!("/api/v3/sor/order?{}", params),
+            request.weight(),
+            1,
+        )
+    }
+
+    ///
+    /// Test new order creation using the Smart Order Routing.
+    /// Creates and validates a new order but does not send it into the matching engine.
+    ///
+    pub fn sor_order_post_test(&self, mut request: OrderPostQuery) -> Result<()> {
+        self.fill_default_symbol(&mut request.symbol);
+        request.validate()?;
+
+        let params = self.stamp_and_sign(&mut request)?;
+
+        self.execute_signed::<Empty>(
+            Method::POST,
+            format!("/api/v3/sor/order/test?{}", params),
+            request.weight(),
+            0,
+        )
+        .map(|_| ())
+    }
+
+    ///
+    /// Cancel an active order.
+    ///
+    pub fn order_delete(&self, mut request: OrderDeleteQuery) -> Result<OrderDeleteResponse> {
+        self.fill_default_symbol(&mut request.symbol);
+        let params = self.stamp_and_sign(&mut request)?;
+
+        self.execute_signed::<OrderDeleteResponse>(
+            Method::DELETE,
+            format!("/api/v3/order?{}", params),
+            request.weight(),
+            0,
+        )
+    }
+
+    ///
+    /// Cancel an active order and wait until it reaches its final state, polling the order every
+    /// 250 milliseconds.
+    ///
+    /// The cancellation may race with a fill, so the final status tells whether the order has
+    /// been actually cancelled. Fails with `OrderCancelConfirmationTimeout` if the order is still
+    /// active after `timeout`.
+    ///
+    pub fn order_delete_and_confirm(
+        &self,
+        request: OrderDeleteQuery,
+        timeout: Duration,
+    ) -> Result<Order> {
+        let started_at = Instant::now();
+        let symbol = request.symbol.clone();
+        let order_id = request.order_id;
+        let orig_client_order_id = request.orig_client_order_id.clone();
+        let recv_window = request.recv_window;
+
+        match self.order_delete(request) {
+            Ok(_) => {}
+            Err(Error::ResponseError(ref error)) if error.code == Self::UNKNOWN_ORDER => {}
+            Err(error) => return Err(error),
+        }
+
+        loop {
+            let order = self.order_get(OrderGetQuery {
+                symbol: symbol.clone(),
+                order_id,
+                orig_client_order_id: orig_client_order_id.clone(),
+                recv_window,
+                timestamp: 0,
+            })?;
+            if order.status.is_done() {
+                return Ok(order);
+            }
+            if started_at.elapsed() >= timeout {
+                return Err(Error::OrderCancelConfirmationTimeout(
+                    symbol,
+                    order.order_id,
+                ));
+            }
+            std::thread::sleep(Self::CANCEL_CONFIRMATION_INTERVAL);
+        }
+    }
+
+    ///
+    /// Check an order list, e.g. an OCO, by either its ID or its client-side ID.
+    ///
+    pub fn order_list_get(&self, mut request: OrderListGetQuery) -> Result<OrderListGetResponse> {
+        let params = self.stamp_and_sign(&mut request)?;
+
+        self.execute_signed::<OrderListGetResponse>(
+            Method::GET,
+            format!("/api/v3/orderList?{}", params),
+            request.weight(),
+            0,
+        )
+    }
+
+    ///
+    /// Get all open order lists, e.g. OCOs.
+    ///
+    pub fn open_order_list_get(
+        &self,
+        mut request: OpenOrderListGetQuery,
+    ) -> Result<OpenOrderListGetResponse> {
+        let params = self.stamp_and_sign(&mut request)?;
+
+        self.execute_signed::<OpenOrderListGetResponse>(
+            Method::GET,
+            format!("/api/v3/openOrderList?{}", params),
+            request.weight(),
+            0,
+        )
+    }
+
+    ///
+    /// Cancel an entire order list, e.g. an OCO.
+    ///
+    pub fn order_list_delete(
+        &self,
+        mut request: OrderListDeleteQuery,
+    ) -> Result<OrderListDeleteResponse> {
+        self.fill_default_symbol(&mut request.symbol);
+        let params = self.stamp_and_sign(&mut request)?;
+
+        self.execute_signed::<OrderListDeleteResponse>(
+            Method::DELETE,
+            format!("/api/v3/orderList?{}", params),
+            request.weight(),
+            0,
+        )
+    }
+
+    ///
+    /// Check an OCO by either its ID or its client-side ID. An alias of `order_list_get`.
+    ///
+    pub fn oco_get(&self, request: OrderListGetQuery) -> Result<OrderListGetResponse> {
+        self.order_list_get(request)
+    }
+
+    ///
+    /// Get all open OCOs. An alias of `open_order_list_get`.
+    ///
+    pub fn open_oco_get(&self, request: OpenOrderListGetQuery) -> Result<OpenOrderListGetResponse> {
+        self.open_order_list_get(request)
+    }
+
+    ///
+    /// Cancel an entire OCO. An alias of `order_list_delete`.
+    ///
+    pub fn oco_delete(&self, request: OrderListDeleteQuery) -> Result<OrderListDeleteResponse> {
+        self.order_list_delete(request)
+    }
+
+    ///
+    /// Reduce the quantity of an existing open order without losing its priority in the queue.
+    ///
+    pub fn order_amend_keep_priority(
+        &self,
+        mut request: OrderAmendKeepPriorityPutQuery,
+    ) -> Result<OrderAmendKeepPriorityPutResponse> {
+        self.fill_default_symbol(&mut request.symbol);
+        let params = self.stamp_and_sign(&mut request)?;
+
+        self.execute_signed::<OrderAmendKeepPriorityPutResponse>(
+            Method::PUT,
+            format!("/api/v3/order/amend/keepPriority?{}", params),
+            request.weight(),
+            0,
+        )
+    }
+
+    ///
+    /// Get the account deposit history.
+    ///
+    /// The SAPI limits are separate from the API v3 ones, so the request weight is not tracked.
+    ///
+    pub fn deposit_history(
+        &self,
+        mut request: DepositHistoryGetQuery,
+    ) -> Result<DepositHistoryGetResponse> {
+        request.validate()?;
+        let params = self.stamp_and_sign(&mut request)?;
+
+        self.execute_signed::<DepositHistoryGetResponse>(
+            Method::GET,
+            format!("/sapi/v1/capital/deposit/hisrec?{}", params),
+            request.weight(),
+            0,
+        )
+    }
+
+    ///
+    /// Get the system status, e.g. to pause the trading during the maintenance.
+    ///
+    /// The SAPI limits are separate from the API v3 ones, so the request weight is not tracked.
+    ///
+    pub fn system_status(&self) -> Result<SystemStatusGetResponse> {
+        self.execute::<SystemStatusGetResponse>(Method::GET, "/sapi/v1/system/status".to_owned(), 0)
+    }
+
+    ///
+    /// Get the account withdraw history.
+    ///
+    /// The SAPI limits are separate from the API v3 ones, so the request weight is not tracked.
+    ///
+    pub fn withdraw_history(
+        &self,
+        mut request: WithdrawHistoryGetQuery,
+    ) -> Result<WithdrawHistoryGetResponse> {
+        request.validate()?;
+        let params = self.stamp_and_sign(&mut request)?;
+
+        self.execute_signed::<WithdrawHistoryGetResponse>(
+            Method::GET,
+            format!("/sapi/v1/capital/withdraw/history?{}", params),
+            request.weight(),
+            0,
+        )
+    }
+
+    ///
+    /// Start a new user data stream, whose listen key is valid for 60 minutes.
+    ///
+    pub fn user_data_stream_post(&self) -> Result<UserDataStreamPostResponse> {
+        self.execute_keyed::<UserDataStreamPostResponse>(
+            Method::POST,
+            "/api/v3/userDataStream".to_owned(),
+            2,
+        )
+    }
+
+    ///
+    /// Keep the user data stream alive for another 60 minutes, which should be done every 30
+    /// minutes.
+    ///
+    pub fn user_data_stream_put(&self, listen_key: &str) -> Result<()> {
+        self.execute_keyed::<Empty>(
+            Method::PUT,
+            format!(
+                "/api/v3/userDataStream?{}",
+                Self::listen_key_query(listen_key)
+            ),
+            2,
+        )
+        .map(|_| ())
+    }
+
+    ///
+    /// Close the user data stream.
+    ///
+    pub fn user_data_stream_delete(&self, listen_key: &str) -> Result<()> {
+        self.execute_keyed::<Empty>(
+            Method::DELETE,
+            format!(
+                "/api/v3/userDataStream?{}",
+                Self::listen_key_query(listen_key)
+            ),
+            2,
+        )
+        .map(|_| ())
+    }
+
+    ///
+    /// Test new order creation and signature/recvWindow long.
+    /// Creates and validates a new order but does not send it into the matching engine.
+    ///
+    pub fn order_post_test(&self, mut request: OrderPostQuery) -> Result<()> {
+        self.fill_default_symbol(&mut request.symbol);
+        request.validate()?;
+
+        let params = self.stamp_and_sign(&mut request)?;
+
+        self.execute_signed::<Empty>(
+            Method::POST,
+            format!("/api/v3/order/test?{}", params),
+            request.weight(),
+            0,
+        )
+        .map(|_| ())
+    }
+
+    ///
+    /// Executes an authorized request to an arbitrary endpoint, e.g. the one not wrapped by the
+    /// client yet.
+    ///
+    /// The `timestamp` is added to `params` unless already set, and the query is signed in the
+    /// same way as by the wrapped endpoints. The request costs `1` for the rate limiter, since
+    /// the endpoint weight is unknown.
+    ///
+    pub fn signed_request<T>(
+        &self,
+        method: Method,
+        path: &str,
+        params: BTreeMap<String, String>,
+    ) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        for (name, value) in params.iter() {
+            query.append_pair(name.as_str(), value.as_str());
+        }
+        if !params.contains_key("timestamp") {
+            let timestamp = Utc::now().timestamp_millis() - self.time_offset();
+            query.append_pair("timestamp", timestamp.to_string().as_str());
+        }
+
+        let params = self.sign(query.finish())?;
+
+        self.execute_signed::<T>(method, format!("{}?{}", path, params), 1, 0)
+    }
+
+    ///
+    /// Executes an unauthorized request, which costs `weight`.
+    ///
+    fn execute<T>(&self, method: Method, url: String, weight: u32) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.execute_raw(method, url, weight)
+            .map(|response| response.value)
+    }
+
+    ///
+    /// Executes a request with the API key, but without the signature, which costs `weight`.
+    ///
+    fn execute_keyed<T>(&self, method: Method, url: String, weight: u32) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let api_key = self
+            .api_key
+            .as_ref()
+            .ok_or(Error::AuthorizationKeysMissing)?;
+
+        self.execute_unsigned_raw(method, url, weight, Some(api_key.as_str()))
+            .map(|response| response.value)
+    }
+
+    ///
+    /// Executes an authorized request, which costs `weight` and places `orders`.
+    ///
+    fn execute_signed<T>(&self, method: Method, url: String, weight: u32, orders: u32) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.execute_signed_raw(method, url, weight, orders)
+            .map(|response| response.value)
+    }
+
+    ///
+    /// Executes an unauthorized request, which costs `weight`, and keeps the raw response body.
+    ///
+    /// The `url` is the path with the query, e.g. `/api/v3/time`.
+    ///
+    pub fn execute_raw<T>(
+        &self,
+        method: Method,
+        url: String,
+        weight: u32,
+    ) -> Result<ResponseWithRaw<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.execute_unsigned_raw(method, url, weight, None)
+    }
+
+    ///
+    /// Executes an unsigned request, which costs `weight`, with the optional `api_key` header,
+    /// and keeps the raw response body.
+    ///
+    fn execute_unsigned_raw<T>(
+        &self,
+        method: Method,
+        url: String,
+        weight: u32,
+        api_key: Option<&str>,
+    ) -> Result<ResponseWithRaw<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let mut attempt = 1;
+        loop {
+            if let Some(ref rate_limiter) = self.rate_limiter {
+                rate_limiter.acquire(weight, 0)?;
+            }
+
+            match self.send(method.clone(), url.clone(), api_key) {
+                Err(error) if self.should_retry(&method, &error, attempt) => {
+                    self.wait_retry(&error, attempt);
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    ///
+    /// Executes an authorized request, which costs `weight` and places `orders`, and keeps the
+    /// raw response body.
+    ///
+    /// The `url` is the path with the query, which must be already signed with `sign`.
+    ///
+    pub fn execute_signed_raw<T>(
+        &self,
+        method: Method,
+        url: String,
+        weight: u32,
+        orders: u32,
+    ) -> Result<ResponseWithRaw<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let api_key = self
+            .api_key
+            .as_ref()
+            .ok_or(Error::AuthorizationKeysMissing)?;
+
+        let mut url = url;
+        let mut attempt = 1;
+        let mut is_resynchronized = false;
+        loop {
+            if let Some(ref rate_limiter) = self.rate_limiter {
+                rate_limiter.acquire(weight, orders)?;
+            }
+
+            match self.send(method.clone(), url.clone(), Some(api_key.as_str())) {
+                Err(Error::ResponseError(ref error))
+                    if self.resync_on_timestamp_error
+                        && !is_resynchronized
+                        && error.code == Self::TIMESTAMP_OUTSIDE_RECV_WINDOW =>
+                {
+                    log::warn!("Request timestamp rejected: {}, resynchronizing", error.msg);
+                    self.synchronize_time()?;
+                    is_resynchronized = true;
+                }
+                Err(error) if self.should_retry(&method, &error, attempt) => {
+                    self.wait_retry(&error, attempt);
+                    attempt += 1;
+                }
+                result => return self.track_clock_drift(result),
+            }
+
+            url = self.resign(url.as_str())?;
+        }
+    }
+
+    ///
+    /// Counts the consecutive `-1021` and `-1022` errors of the signed requests, and replaces
+    /// the error with `ClockDriftSuspected` once the threshold is reached.
+    ///
+    fn track_clock_drift<T>(&self, result: Result<T>) -> Result<T> {
+        match result {
+            Err(Error::ResponseError(error))
+                if error.code == Self::TIMESTAMP_OUTSIDE_RECV_WINDOW
+                    || error.code == Self::INVALID_SIGNATURE =>
+            {
+                let failures = self.timestamp_failures.fetch_add(1, Ordering::SeqCst) + 1;
+                if failures < self.clock_drift_threshold {
+                    return Err(Error::ResponseError(error));
+                }
+                log::error!(
+                    "{} consecutive timestamp or signature errors, the clock drift is suspected",
+                    failures
+                );
+                Err(Error::ClockDriftSuspected(failures, error))
+            }
+            Ok(response) => {
+                self.timestamp_failures.store(0, Ordering::SeqCst);
+                Ok(response)
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    ///
+    /// If the failed `attempt` of a request may be retried according to the retry policy.
+    ///
+    fn should_retry(&self, method: &Method, error: &Error, attempt: u32) -> bool {
+        method.is_idempotent() && self.retry_policy.is_retryable(error, attempt)
+    }
+
+    ///
+    /// Sleeps for the retry policy delay after the failed `attempt`.
+    ///
+    fn wait_retry(&self, error: &Error, attempt: u32) {
+        let delay = self.retry_policy.delay(attempt);
+        log::warn!(
+            "Request attempt {} failed: {}, retrying in {:?}",
+            attempt,
+            error,
+            delay
+        );
+        std::thread::sleep(delay);
+    }
+
+    ///
+    /// Sets the default symbol to the query with an empty `symbol`, if set in the builder.
+    ///
+    fn fill_default_symbol(&self, symbol: &mut String) {
+        if let (true, Some(default_symbol)) = (symbol.is_empty(), self.default_symbol.as_ref()) {
+            symbol.push_str(default_symbol.as_str());
+        }
+    }
+
+    ///
+    /// Re-signs the signed request `url` with the current timestamp.
+    ///
+    fn resign(&self, url: &str) -> Result<String> {
+        let (path, query) = url.split_at(url.find('?').unwrap_or(url.len()));
+        let query = query.trim_start_matches('?');
+
+        let mut params = form_urlencoded::Serializer::new(String::with_capacity(query.len()));
+        for (name, value) in form_urlencoded::parse(query.as_bytes()) {
+            match name.as_ref() {
+                "signature" => {}
+                "timestamp" => {
+                    let timestamp = Utc::now().timestamp_millis() - self.time_offset();
+                    params.append_pair("timestamp", timestamp.to_string().as_str());
+                }
+                _ => {
+                    params.append_pair(name.as_ref(), value.as_ref());
+                }
+            }
+        }
+
+        Ok(format!("{}?{}", path, self.sign(params.finish())?))
+    }
+
+    ///
+    /// Sends the request to the `url` path and parses the response.
+    ///
+    /// The `api_key` header is only set for authorized requests.
+    ///
+    /// The request is logged at the debug level with the signature redacted.
+    ///
+    /// With the `tracing` feature, the request is wrapped into a span with the method, endpoint
+    /// path, status, latency, and used weight. The query and headers are never recorded, since
+    /// they contain the signature and API key.
+    ///
+    /// On a connection failure, idempotent requests are retried against the next base URL, which
+    /// is then used for the subsequent requests. Non-idempotent ones, e.g. placing orders, are not
+    /// retried, since the failed request might have reached Binance.
+    ///
+    fn send<T>(
+        &self,
+        method: Method,
+        url: String,
+        api_key: Option<&str>,
+    ) -> Result<ResponseWithRaw<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "binance_request",
+            method = %method,
+            endpoint = url.split('?').next().unwrap_or_default(),
+            status = tracing::field::Empty,
+            latency_ms = tracing::field::Empty,
+            used_weight = tracing::field::Empty,
+            error_code = tracing::field::Empty,
+        );
+        #[cfg(feature = "tracing")]
+        let _span_guard = span.enter();
+        #[cfg(feature = "tracing")]
+        let started_at = Instant::now();
+
+        log::debug!(
+            "Sending {} {}",
+            method,
+            Self::redact_signature(url.as_str())
+        );
+
+        let attempts = if method.is_idempotent() {
+            self.base_urls.len()
+        } else {
+            1
+        };
+
+        let mut index = self.base_url_index.load(Ordering::Relaxed) % self.base_urls.len();
+        let mut attempt = 1;
+        let response = loop {
+            let base_url = self.base_urls[index].as_str();
+            let full_url = base_url.to_owned() + url.as_str();
+
+            let request = TransportRequest {
+                method: method.clone(),
+                url: Url::parse(&full_url).map_err(|error| Error::UrlParsing(error, full_url))?,
+                api_key: api_key.map(|api_key| api_key.to_owned()),
+            };
+
+            match self.transport.send(request) {
+                Ok(response) => break response,
+                Err(error @ Error::RequestExecution(_)) | Err(error @ Error::Connection(_))
+                    if attempt < attempts =>
+                {
+                    index = (index + 1) % self.base_urls.len();
+                    log::warn!(
+                        "Request to {} failed: {}, failing over to {}",
+                        base_url,
+                        error,
+                        self.base_urls[index]
+                    );
+                    self.base_url_index.store(index, Ordering::Relaxed);
+                    attempt += 1;
+                }
+                Err(error) => return Err(error),
+            }
+        };
+
+        #[cfg(feature = "tracing")]
+        {
+            span.record("status", response.status.as_u16());
+            span.record("latency_ms", started_at.elapsed().as_millis() as u64);
+            if let Some(ref used_weight) = response.used_weight {
+                span.record("used_weight", used_weight.as_str());
+            }
+        }
+
+        let status = response.status;
+        let is_json = response
+            .content_type
+            .as_ref()
+            .map(|value| value.contains("json"))
+            .unwrap_or(true);
+        let raw = response.body;
+        if status.is_server_error() {
+            return Err(Error::ServerError(
+                status,
+                raw.chars().take(Self::RESPONSE_EXCERPT_LENGTH).collect(),
+            ));
+        }
+        if !is_json || raw.trim().is_empty() || raw.trim_start().starts_with('<') {
+            return Err(Error::NonJsonResponse(
+                status,
+                raw.chars().take(Self::RESPONSE_EXCERPT_LENGTH).collect(),
+            ));
+        }
+        let response: Response<T> = serde_json::from_str(raw.as_str())
+            .map_err(|error| Error::ResponseParsing(error, raw.clone()))?;
+
+        match response {
+            Response::Ok(value) => Ok(ResponseWithRaw { value, raw }),
+            Response::Error(error) => {
+                #[cfg(feature = "tracing")]
+                span.record("error_code", error.code);
+                if error.code == Self::INVALID_API_KEY {
+                    return Err(Error::InvalidApiKey(error));
+                }
+                Err(Error::ResponseError(error))
+            }
+        }
+    }
+
+    ///
+    /// Replaces the signature value in the request `url` with `***`, so the request may be
+    /// logged, e.g. to see the exact parameters of a rejected order.
+    ///
+    pub fn redact_signature(url: &str) -> String {
+        let (path, query) = match url.find('?') {
+            Some(index) => (&url[..=index], &url[index + 1..]),
+            None => ("", url),
+        };
+
+        let query = query
+            .split('&')
+            .map(|pair| {
+                if pair.starts_with("signature=") {
+                    "signature=***"
+                } else {
+                    pair
+                }
+            })
+            .collect::<Vec<&str>>()
+            .join("&");
+        format!("{}{}", path, query)
+    }
+
+    ///
+    /// The user data stream query with the listen key.
+    ///
+    fn listen_key_query(listen_key: &str) -> String {
+        form_urlencoded::Serializer::new(String::new())
+            .append_pair("listenKey", listen_key)
+            .finish()
+    }
+
+    ///
+    /// Checks that the order symbol status is `TRADING` in the cached exchange info, if enabled.
+    ///
+    /// The unknown symbols are left for Binance to reject.
+    ///
+    fn check_symbol_status(&self, symbol: &str) -> Result<()> {
+        if !self.check_symbol_status {
+            return Ok(());
+        }
+
+        match self.exchange_info_cached()?.symbol(symbol) {
+            Some(data) if !data.is_trading() => {
+                Err(Error::SymbolNotTrading(symbol.to_owned(), data.status))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    ///
+    /// Stamps the signed request query with the current time reduced by `timestamp_offset`,
+    /// and signs it.
+    ///
+    fn stamp_and_sign<Q>(&self, request: &mut Q) -> Result<String>
+    where
+        Q: SignedQuery,
+    {
+        request.set_timestamp(Utc::now().timestamp_millis() - self.time_offset());
+        self.sign(request.to_string())
+    }
+
+    ///
+    /// Validates the signed request query and appends the signature to it.
+    ///
+    /// The default `recvWindow` is appended before signing, unless the query sets its own.
+    /// Fails with `InvalidRequest` if the `recvWindow` exceeds `RECV_WINDOW_MAX`.
+    ///
+    /// The query timestamp must be already reduced by `timestamp_offset`.
+    ///
+    pub fn sign(&self, params: String) -> Result<String> {
+        let authorization = self
+            .authorization
+            .as_ref()
+            .ok_or(Error::AuthorizationKeysMissing)?;
+
+        let params = match self.recv_window {
+            Some(recv_window)
+                if !params
+                    .split('&')
+                    .any(|pair| pair.starts_with("recvWindow=")) =>
+            {
+                form_urlencoded::Serializer::for_suffix(params, 0)
+                    .append_pair("recvWindow", recv_window.to_string().as_str())
+                    .finish()
+            }
+            _ => params,
+        };
+
+        Self::validate_signed_params(params.as_str())?;
+
+        let signature = authorization.sign(params.as_str());
+        Ok(form_urlencoded::Serializer::for_suffix(params, 0)
+            .append_pair("signature", signature.as_str())
+            .finish())
+    }
+
+    ///
+    /// Checks that the signed request query is well-formed, since Binance would only reply with
+    /// the `-1022 Signature for this request is not valid` error otherwise.
+    ///
+    fn validate_signed_params(params: &str) -> Result<()> {
+        let mut has_timestamp = false;
+        for pair in params.split('&').filter(|pair| !pair.is_empty()) {
+            let mut pair = pair.splitn(2, '=');
+            let name = pair.next().unwrap_or_default();
+            let value = pair.next().unwrap_or_default();
+            if name.is_empty() {
+                return Err(Error::InvalidSignedRequest(format!(
+                    "parameter without a name in `{}`",
+                    params
+                )));
+            }
+            if value.is_empty() {
+                return Err(Error::InvalidSignedRequest(format!(
+                    "parameter `{}` is empty",
+                    name
+                )));
+            }
+            if name == "timestamp" {
+                has_timestamp = true;
+            }
+            if let ("recvWindow", Ok(recv_window)) = (name, value.parse()) {
+                Self::validate_recv_window(recv_window)?;
+            }
+        }
+        if !has_timestamp {
+            return Err(Error::InvalidSignedRequest(
+                "parameter `timestamp` is missing".to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Checks that the `recvWindow` is positive and at most `RECV_WINDOW_MAX`, since Binance
+    /// rejects the larger ones.
+    ///
+    pub(crate) fn validate_recv_window(recv_window: i64) -> Result<()> {
+        if recv_window <= 0 || recv_window > Self::RECV_WINDOW_MAX {
+            return Err(Error::InvalidRequest(format!(
+                "recvWindow {} is out of the range 1..={}",
+                recv_window,
+                Self::RECV_WINDOW_MAX
+            )));
+        }
+        Ok(())
+    }
+
+    ///
+    /// Generates a unique client order ID, which satisfies the Binance `^[.A-Z:/a-z0-9_-]{1,36}$`
+    /// format.
+    ///
+    fn generate_client_order_id() -> String {
+        format!(
+            "{:x}{:016x}",
+            Utc::now().timestamp_millis(),
+            RandomState::new().build_hasher().finish()
+        )
+    }
+
+    ///
+    /// Calculates the request timestamp offsets between the system time and Binance time.
+    ///
+    fn calculate_timestamp_offset(&self) -> Result<i64> {
+        Ok(self.measure_time_sync()?.offset_ms + self.timestamp_padding)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::thread;
+
+    use reqwest::StatusCode;
+    use reqwest::Url;
+    use rust_decimal::Decimal;
+
+    use crate::error::Error;
+    use crate::http_api_v3::data::order_side::OrderSide;
+
+    use super::transport::Transport;
+    use super::transport::TransportRequest;
+    use super::transport::TransportResponse;
+    use super::Client;
+    use super::OrderPostQuery;
+    use super::PriceTickerGetResponse;
+
+    ///
+    /// The fake transport, which responds with the server time or with the function result.
+    ///
+    #[derive(Debug)]
+    struct FakeTransport(fn(&Url) -> String);
+
+    impl Transport for FakeTransport {
+        fn send(&self, request: TransportRequest) -> Result<TransportResponse, Error> {
+            let body = match request.url.path() {
+                "/api/v3/time" => r#"{"serverTime":1499827319559}"#.to_owned(),
+                _ => (self.0)(&request.url),
+            };
+            Ok(TransportResponse {
+                status: StatusCode::OK,
+                content_type: Some("application/json;charset=UTF-8".to_owned()),
+                used_weight: None,
+                body,
+            })
+        }
+    }
+
+    fn client_with(respond: fn(&Url) -> String) -> Client {
+        Client::builder()
+            .api_key("api_key".to_owned())
+            .secret_key("secret_key".to_owned())
+            .transport(Arc::new(FakeTransport(respond)))
+            .build()
+            .expect("Fake transport")
+    }
+
+    fn client() -> Client {
+        client_with(|_| "{}".to_owned())
+    }
+
+    #[test]
+    fn ping_accepts_empty_object() {
+        client().ping().expect("Empty object");
+    }
+
+    #[test]
+    fn order_post_test_accepts_empty_object() {
+        let request = OrderPostQuery::limit(
+            "BTCUSDT",
+            OrderSide::Buy,
+            Decimal::new(1, 0),
+            Decimal::new(1, 0),
+        );
+        client().order_post_test(request).expect("Empty object");
+    }
+
+    #[test]
+    fn shared_client_is_usable_across_threads() {
+        let client = Arc::new(client());
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let client = client.clone();
+                thread::spawn(move || client.ping())
+            })
+            .collect();
+        for handle in handles.into_iter() {
+            handle
+                .join()
+                .expect("Thread panicked")
+                .expect("Empty object");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "All orders worker panicked")]
+    fn all_orders_multi_worker_panic_is_raised() {
+        let client = client_with(
+            |url| match url.query_pairs().find(|(key, _)| key == "symbol") {
+                Some((_, symbol)) if symbol == "ETHBTC" => panic!("Fake worker panic"),
+                _ => "[]".to_owned(),
+            },
+        );
+        let _ = client.all_orders_multi(vec!["BTCUSDT".to_owned(), "ETHBTC".to_owned()], 2);
+    }
+
+    ///
+    /// The account trade with the `id`, `price`, and `qty`.
+    ///
+    fn my_trade(id: i64, price: &str, qty: &str) -> String {
+        format!(
+            r#"{{"symbol":"BTCUSDT","id":{},"orderId":1,"orderListId":-1,"price":"{}","qty":"{}","quoteQty":"0","commission":"0","commissionAsset":"BNB","time":1499865549590,"isBuyer":true,"isMaker":false,"isBestMatch":true}}"#,
+            id, price, qty
+        )
+    }
+
+    #[test]
+    fn order_avg_price_pages_trades() {
+        let client = client_with(|url| {
+            let from_id = url
+                .query_pairs()
+                .find(|(key, _)| key == "fromId")
+                .map(|(_, value)| value.into_owned());
+            let trades: Vec<String> = match from_id.as_deref() {
+                None => (1..=1000).map(|id| my_trade(id, "10", "1")).collect(),
+                Some("1001") => vec![my_trade(1001, "40", "3")],
+                Some(from_id) => panic!("unexpected fromId {}", from_id),
+            };
+            format!("[{}]", trades.join(","))
+        });
+
+        let price = client
+            .order_avg_price("BTCUSDT", 1)
+            .expect("Fake trades")
+            .expect("Filled order");
+        assert_eq!(price, Decimal::new(10120, 0) / Decimal::new(1003, 0));
+    }
+
+    #[test]
+    fn order_avg_price_without_trades_is_none() {
+        let client = client_with(|_| "[]".to_owned());
+        assert_eq!(
+            client.order_avg_price("BTCUSDT", 1).expect("Fake trades"),
+            None
+        );
+    }
+
+    #[test]
+    fn time_offset_is_measured_on_build() {
+        let client = client();
+        let time_sync = client.measure_time_sync().expect("Fake server time");
+        assert_eq!(
+            time_sync.server_ms,
+            1499827319559 - time_sync.round_trip_ms / 2
+        );
+        assert!(client.time_offset() > 0);
+    }
+
+    #[test]
+    fn price_ticker_by_symbol_requests_single_or_all() {
+        let client = client_with(|url| {
+            match url.query() {
+            Some("symbol=BTCUSDT") => r#"{"symbol":"BTCUSDT","price":"4.00000200"}"#.to_owned(),
+            _ => r#"[{"symbol":"BTCUSDT","price":"4.00000200"},{"symbol":"ETHBTC","price":"0.07946600"}]"#
+                .to_owned(),
+        }
+        });
+
+        match client
+            .price_ticker_by_symbol(Some("BTCUSDT".to_owned()))
+            .expect("Fake ticker")
+        {
+            PriceTickerGetResponse::Single(ticker) => assert_eq!(ticker.symbol, "BTCUSDT"),
+            response => panic!("expected a single ticker, got {:?}", response),
+        }
+        let tickers = client
+            .price_ticker_by_symbol(None)
+            .expect("Fake tickers")
+            .into_vec();
+        assert_eq!(tickers.len(), 2);
     }
 }
@@ -0,0 +1,171 @@
+//!
+//! The Binance API v3 client-side rate limiter.
+//!
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::error::Error;
+use crate::http_api_v3::data::rate_limit::r#type::RateLimitType;
+use crate::http_api_v3::data::rate_limit::RateLimit;
+
+///
+/// The rate limiter behavior when a request does not fit into the limits.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// Blocks the calling thread until the request fits into the limits.
+    Blocking,
+    /// Returns the `LocalRateLimit` error immediately.
+    Failing,
+}
+
+///
+/// The client-side rate limiter, which tracks the request usage within the rolling time windows
+/// described by the exchange info rate limits.
+///
+#[derive(Debug)]
+pub struct RateLimiter {
+    /// The behavior on exceeding the limits.
+    mode: Mode,
+    /// The tracked rolling windows.
+    windows: Mutex<Vec<Window>>,
+}
+
+///
+/// A single rolling time window.
+///
+#[derive(Debug)]
+struct Window {
+    /// The rate limit description.
+    rate_limit: RateLimit,
+    /// The time window duration.
+    duration: Duration,
+    /// The usage entries within the time window.
+    entries: VecDeque<(Instant, u32)>,
+    /// The sum of the usage entries.
+    used: u32,
+}
+
+impl RateLimiter {
+    ///
+    /// Creates a rate limiter from the exchange info rate limits.
+    ///
+    pub fn new(rate_limits: &[RateLimit], mode: Mode) -> Self {
+        let windows = rate_limits
+            .iter()
+            .filter(|rate_limit| rate_limit.rate_limit_type != RateLimitType::Other)
+            .map(|rate_limit| Window {
+                rate_limit: *rate_limit,
+                duration: rate_limit.window(),
+                entries: VecDeque::new(),
+                used: 0,
+            })
+            .collect();
+
+        Self {
+            mode,
+            windows: Mutex::new(windows),
+        }
+    }
+
+    ///
+    /// Acquires the request `weight` and the number of placed `orders` from all the windows.
+    ///
+    /// Depending on the mode, either waits until the request fits into the limits, or returns
+    /// the `LocalRateLimit` error.
+    ///
+    pub fn acquire(&self, weight: u32, orders: u32) -> Result<(), Error> {
+        loop {
+            let wait = {
+                let mut windows = self.windows.lock().expect("Rate limiter lock");
+                let now = Instant::now();
+
+                let mut wait = None;
+                for window in windows.iter_mut() {
+                    window.expire(now);
+
+                    let cost = window.cost(weight, orders);
+                    if cost > window.rate_limit.limit {
+                        return Err(Error::LocalRateLimit(format!(
+                            "the request cost {} exceeds the {:?} limit of {}",
+                            cost, window.rate_limit.rate_limit_type, window.rate_limit.limit
+                        )));
+                    }
+                    if window.used + cost > window.rate_limit.limit {
+                        let until = window.available_after(now, cost);
+                        wait = Some(wait.map_or(until, |wait: Duration| wait.max(until)));
+                        if let Mode::Failing = self.mode {
+                            return Err(Error::LocalRateLimit(format!(
+                                "the {:?} limit of {} per {:?} is reached",
+                                window.rate_limit.rate_limit_type,
+                                window.rate_limit.limit,
+                                window.duration
+                            )));
+                        }
+                    }
+                }
+
+                match wait {
+                    Some(wait) => wait,
+                    None => {
+                        for window in windows.iter_mut() {
+                            let cost = window.cost(weight, orders);
+                            if cost > 0 {
+                                window.entries.push_back((now, cost));
+                                window.used += cost;
+                            }
+                        }
+                        return Ok(());
+                    }
+                }
+            };
+
+            log::debug!("Rate limiter is waiting for {:?}", wait);
+            std::thread::sleep(wait);
+        }
+    }
+}
+
+impl Window {
+    ///
+    /// Removes the entries, which have left the time window.
+    ///
+    fn expire(&mut self, now: Instant) {
+        while let Some((time, cost)) = self.entries.front().copied() {
+            if now.duration_since(time) < self.duration {
+                break;
+            }
+            self.entries.pop_front();
+            self.used -= cost;
+        }
+    }
+
+    ///
+    /// The request cost in terms of the window rate limit type.
+    ///
+    fn cost(&self, weight: u32, orders: u32) -> u32 {
+        match self.rate_limit.rate_limit_type {
+            RateLimitType::RequestWeight => weight,
+            RateLimitType::Orders => orders,
+            RateLimitType::RawRequests => 1,
+            RateLimitType::Other => 0,
+        }
+    }
+
+    ///
+    /// The time to wait until `cost` fits into the window.
+    ///
+    fn available_after(&self, now: Instant, cost: u32) -> Duration {
+        let mut used = self.used;
+        for (time, entry_cost) in self.entries.iter() {
+            used -= entry_cost;
+            if used + cost <= self.rate_limit.limit {
+                return (*time + self.duration).saturating_duration_since(now);
+            }
+        }
+        Duration::default()
+    }
+}
@@ -17,3 +17,24 @@ pub enum Response<T> {
     /// The error response.
     Error(ResponseError),
 }
+
+///
+/// The deserialized response together with its raw body.
+///
+#[derive(Debug, Clone)]
+pub struct ResponseWithRaw<T> {
+    /// The deserialized response.
+    pub value: T,
+    /// The raw response body.
+    pub raw: String,
+}
+
+///
+/// The empty `{}` response, e.g. of the ping endpoint.
+///
+/// The unit type cannot be used instead, since it is only deserialized from `null`. The unknown
+/// fields are denied, so the error responses are not mistaken for the empty one.
+///
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(deny_unknown_fields)]
+pub struct Empty {}
@@ -0,0 +1,113 @@
+//!
+//! The Binance API v3 request retry policy.
+//!
+
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::hash::Hasher;
+use std::time::Duration;
+
+use crate::error::Error;
+
+///
+/// The request retry policy with the exponential backoff.
+///
+/// Only the idempotent requests are retried, since a failed non-idempotent one, e.g. placing an
+/// order, might have reached Binance. The signed requests are re-signed with a fresh timestamp
+/// before each retry.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RetryPolicy {
+    /// The maximal number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// The delay before the first retry, which is doubled on each next one.
+    pub base_delay: Duration,
+    /// The maximal delay between the attempts.
+    pub max_delay: Duration,
+    /// Whether to randomize the delays, so the clients do not retry simultaneously.
+    pub jitter: bool,
+    /// Whether to retry on the network errors.
+    pub retry_network_errors: bool,
+    /// Whether to retry on the HTTP 5xx responses.
+    pub retry_server_errors: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl RetryPolicy {
+    ///
+    /// Creates a policy retrying on the network errors and HTTP 5xx responses with jitter.
+    ///
+    pub fn new(max_attempts: u32, base_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            max_attempts,
+            base_delay,
+            max_delay,
+            jitter: true,
+            retry_network_errors: true,
+            retry_server_errors: true,
+        }
+    }
+
+    ///
+    /// Creates a policy, which never retries. The default one.
+    ///
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            base_delay: Duration::default(),
+            max_delay: Duration::default(),
+            jitter: false,
+            retry_network_errors: false,
+            retry_server_errors: false,
+        }
+    }
+
+    ///
+    /// If the failed `attempt` of a request may be retried after `error`.
+    ///
+    /// The Binance application-level errors are never retried, since the request was rejected.
+    ///
+    pub fn is_retryable(&self, error: &Error, attempt: u32) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+
+        match error {
+            Error::RequestExecution(_) | Error::ResponseReading(_) | Error::Connection(_) => {
+                self.retry_network_errors
+            }
+            Error::ServerError(..) => self.retry_server_errors,
+            _ => false,
+        }
+    }
+
+    ///
+    /// The delay after the failed `attempt`, that is, `base_delay * 2^(attempt - 1)` limited by
+    /// `max_delay`.
+    ///
+    /// With jitter, the delay is randomized between its half and its full value.
+    ///
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let multiplier = 1u32
+            .checked_shl(attempt.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        let delay = self
+            .base_delay
+            .checked_mul(multiplier)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+
+        if self.jitter {
+            let half = delay / 2;
+            let random = RandomState::new().build_hasher().finish();
+            half + Duration::from_nanos(random % (half.as_nanos() as u64 + 1))
+        } else {
+            delay
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//!
+//! The Binance API v3 signed request query.
+//!
+
+///
+/// The query of a signed request, which is stamped with the synchronized time by the client
+/// right before signing, so the callers never set the timestamp themselves.
+///
+pub trait SignedQuery: ToString {
+    ///
+    /// Sets the request time in milliseconds.
+    ///
+    fn set_timestamp(&mut self, timestamp: i64);
+}
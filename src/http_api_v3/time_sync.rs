@@ -0,0 +1,20 @@
+//!
+//! The Binance API v3 time synchronization measurement.
+//!
+
+///
+/// The clock skew between the system and Binance, measured with a single time request.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimeSync {
+    /// The system time in milliseconds, when the request was sent.
+    pub system_ms: i64,
+    /// The Binance time in milliseconds, reduced by a half of the round trip to estimate the
+    /// server time at the moment the request was sent.
+    pub server_ms: i64,
+    /// The clock skew in milliseconds, that is, `system_ms - server_ms`. Positive if the system
+    /// clock is ahead of Binance.
+    pub offset_ms: i64,
+    /// The request round-trip time in milliseconds.
+    pub round_trip_ms: i64,
+}
@@ -0,0 +1,528 @@
+//!
+//! The Binance API v3 HTTP transport.
+//!
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+use std::net::SocketAddr;
+use std::net::TcpStream;
+use std::time::Duration;
+
+use reqwest::Method;
+use reqwest::StatusCode;
+use reqwest::Url;
+
+use crate::error::Error;
+
+///
+/// The HTTP transport, which sends the prepared requests.
+///
+/// The default one is backed by `reqwest`, but a fake one may be set in the builder to test
+/// the response parsing and error mapping offline.
+///
+pub trait Transport: fmt::Debug + Send + Sync {
+    ///
+    /// Sends the request and reads the response.
+    ///
+    /// The connection failures must be reported as `RequestExecution` or `Connection`, so the
+    /// client can fail over to the next base URL.
+    ///
+    fn send(&self, request: TransportRequest) -> Result<TransportResponse, Error>;
+}
+
+///
+/// The HTTP request prepared by the client.
+///
+#[derive(Debug, Clone)]
+pub struct TransportRequest {
+    /// The HTTP method.
+    pub method: Method,
+    /// The full URL with the signed query.
+    pub url: Url,
+    /// The `X-MBX-APIKEY` header value, which is only set for authorized requests.
+    pub api_key: Option<String>,
+}
+
+///
+/// The HTTP response read by the transport.
+///
+#[derive(Debug, Clone)]
+pub struct TransportResponse {
+    /// The HTTP status.
+    pub status: StatusCode,
+    /// The `Content-Type` header value.
+    pub content_type: Option<String>,
+    /// The `X-MBX-USED-WEIGHT-1M` header value.
+    pub used_weight: Option<String>,
+    /// The response body.
+    pub body: String,
+}
+
+///
+/// The default `reqwest` transport.
+///
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    /// The inner HTTP client.
+    inner: reqwest::Client,
+    /// The maximal response body size in bytes.
+    max_response_size: usize,
+}
+
+///
+/// The transport, which connects to the pinned socket addresses instead of resolving the hosts,
+/// e.g. to reach the closest Binance edge server or to keep working during a DNS outage.
+///
+/// The TLS server name and the `Host` header are still set to the URL host. The requests to
+/// the hosts without a pinned address are sent through the inner `reqwest` transport. The pinned
+/// connections use HTTP/1.1 without compression and are not reused.
+///
+#[derive(Debug, Clone)]
+pub struct ResolvingTransport {
+    /// The transport of the hosts without a pinned address.
+    inner: ReqwestTransport,
+    /// The pinned socket addresses by host.
+    addresses: HashMap<String, SocketAddr>,
+    /// The TLS connector of the `https` URLs.
+    tls: native_tls::TlsConnector,
+    /// The `User-Agent` header value.
+    user_agent: Option<String>,
+    /// The maximal response body size in bytes.
+    max_response_size: usize,
+}
+
+///
+/// The response body buffer, which refuses to grow beyond the limit.
+///
+struct LimitedBuffer {
+    /// The body read so far.
+    body: Vec<u8>,
+    /// The maximal body size in bytes.
+    limit: usize,
+    /// Whether the body has exceeded the limit.
+    is_exceeded: bool,
+}
+
+impl ReqwestTransport {
+    /// The default maximal response body size in bytes.
+    pub const MAX_RESPONSE_SIZE_DEFAULT: usize = 32 * 1024 * 1024;
+
+    ///
+    /// Wraps the configured `reqwest` client.
+    ///
+    pub fn new(inner: reqwest::Client) -> Self {
+        Self {
+            inner,
+            max_response_size: Self::MAX_RESPONSE_SIZE_DEFAULT,
+        }
+    }
+
+    ///
+    /// Sets the maximal response body size in bytes, beyond which the response is rejected with
+    /// `ResponseTooLarge` instead of being read into memory.
+    ///
+    pub fn max_response_size(mut self, max_response_size: usize) -> Self {
+        self.max_response_size = max_response_size;
+        self
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: TransportRequest) -> Result<TransportResponse, Error> {
+        let mut builder = self.inner.request(request.method, request.url);
+        if let Some(api_key) = request.api_key {
+            builder = builder.header("X-MBX-APIKEY", api_key);
+        }
+
+        let mut response = self
+            .inner
+            .execute(builder.build().map_err(Error::RequestBuilding)?)
+            .map_err(Error::RequestExecution)?;
+
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.to_owned())
+        };
+        let content_type = header(reqwest::header::CONTENT_TYPE.as_str());
+        let used_weight = header("X-MBX-USED-WEIGHT-1M");
+
+        if let Some(length) = response.content_length() {
+            if length > self.max_response_size as u64 {
+                return Err(Error::ResponseTooLarge(self.max_response_size));
+            }
+        }
+
+        let mut buffer = LimitedBuffer {
+            body: Vec::new(),
+            limit: self.max_response_size,
+            is_exceeded: false,
+        };
+        if let Err(error) = response.copy_to(&mut buffer) {
+            if buffer.is_exceeded {
+                return Err(Error::ResponseTooLarge(self.max_response_size));
+            }
+            return Err(Error::ResponseReading(error));
+        }
+
+        Ok(TransportResponse {
+            status: response.status(),
+            content_type,
+            used_weight,
+            body: String::from_utf8_lossy(buffer.body.as_slice()).into_owned(),
+        })
+    }
+}
+
+impl ResolvingTransport {
+    /// The connection, reading, and writing timeout of the pinned connections.
+    pub const TIMEOUT: Duration = Duration::from_secs(30);
+
+    ///
+    /// Wraps the transport of the hosts without a pinned address.
+    ///
+    pub fn new(
+        inner: ReqwestTransport,
+        addresses: HashMap<String, SocketAddr>,
+        tls: native_tls::TlsConnector,
+    ) -> Self {
+        Self {
+            inner,
+            addresses,
+            tls,
+            user_agent: None,
+            max_response_size: ReqwestTransport::MAX_RESPONSE_SIZE_DEFAULT,
+        }
+    }
+
+    ///
+    /// Sets the `User-Agent` header value of the pinned connections.
+    ///
+    pub fn user_agent(mut self, user_agent: String) -> Self {
+        self.user_agent = Some(user_agent);
+        self
+    }
+
+    ///
+    /// Sets the maximal response body size in bytes of the pinned connections, beyond which the
+    /// response is rejected with `ResponseTooLarge`.
+    ///
+    pub fn max_response_size(mut self, max_response_size: usize) -> Self {
+        self.max_response_size = max_response_size;
+        self
+    }
+
+    ///
+    /// Writes the request to the connected stream and reads the response.
+    ///
+    fn exchange<S>(
+        &self,
+        mut stream: S,
+        request: TransportRequest,
+    ) -> Result<TransportResponse, Error>
+    where
+        S: Read + Write,
+    {
+        let url = &request.url;
+        let mut target = url.path().to_owned();
+        if let Some(query) = url.query() {
+            target.push('?');
+            target.push_str(query);
+        }
+        let mut host = url.host_str().unwrap_or_default().to_owned();
+        if let Some(port) = url.port() {
+            host = format!("{}:{}", host, port);
+        }
+
+        let mut head = format!(
+            "{} {} HTTP/1.1\r\nHost: {}\r\nAccept: */*\r\nConnection: close\r\n",
+            request.method, target, host
+        );
+        if let Some(ref user_agent) = self.user_agent {
+            head.push_str(format!("User-Agent: {}\r\n", user_agent).as_str());
+        }
+        if let Some(ref api_key) = request.api_key {
+            head.push_str(format!("X-MBX-APIKEY: {}\r\n", api_key).as_str());
+        }
+        if request.method != Method::GET {
+            head.push_str("Content-Length: 0\r\n");
+        }
+        head.push_str("\r\n");
+        stream
+            .write_all(head.as_bytes())
+            .and_then(|()| stream.flush())
+            .map_err(Error::Connection)?;
+
+        let mut reader = BufReader::new(stream);
+        let status_line = Self::read_line(&mut reader)?;
+        let status = status_line
+            .split(' ')
+            .nth(1)
+            .and_then(|code| code.parse::<u16>().ok())
+            .and_then(|code| StatusCode::from_u16(code).ok())
+            .ok_or_else(|| Self::invalid_data(format!("status line {:?}", status_line)))?;
+
+        let mut content_type = None;
+        let mut used_weight = None;
+        let mut content_length = None;
+        let mut is_chunked = false;
+        loop {
+            let line = Self::read_line(&mut reader)?;
+            if line.is_empty() {
+                break;
+            }
+            let (name, value) = match line.find(':') {
+                Some(index) => (&line[..index], line[index + 1..].trim()),
+                None => return Err(Self::invalid_data(format!("header {:?}", line))),
+            };
+            if name.eq_ignore_ascii_case("Content-Type") {
+                content_type = Some(value.to_owned());
+            } else if name.eq_ignore_ascii_case("X-MBX-USED-WEIGHT-1M") {
+                used_weight = Some(value.to_owned());
+            } else if name.eq_ignore_ascii_case("Content-Length") {
+                content_length = Some(
+                    value
+                        .parse::<u64>()
+                        .map_err(|_| Self::invalid_data(format!("Content-Length {:?}", value)))?,
+                );
+            } else if name.eq_ignore_ascii_case("Transfer-Encoding") {
+                is_chunked = value.eq_ignore_ascii_case("chunked");
+            }
+        }
+
+        let mut body = Vec::new();
+        if is_chunked {
+            loop {
+                let line = Self::read_line(&mut reader)?;
+                let size = line.split(';').next().unwrap_or_default().trim();
+                let size = u64::from_str_radix(size, 16)
+                    .map_err(|_| Self::invalid_data(format!("chunk size {:?}", line)))?;
+                if size == 0 {
+                    break;
+                }
+                self.read_body(&mut reader, &mut body, size)?;
+                Self::read_line(&mut reader)?;
+            }
+        } else if let Some(length) = content_length {
+            self.read_body(&mut reader, &mut body, length)?;
+        } else {
+            let limit = (self.max_response_size - body.len()) as u64;
+            reader
+                .take(limit + 1)
+                .read_to_end(&mut body)
+                .map_err(Error::Connection)?;
+            if body.len() > self.max_response_size {
+                return Err(Error::ResponseTooLarge(self.max_response_size));
+            }
+        }
+
+        Ok(TransportResponse {
+            status,
+            content_type,
+            used_weight,
+            body: String::from_utf8_lossy(body.as_slice()).into_owned(),
+        })
+    }
+
+    ///
+    /// Reads exactly `size` more bytes of the body, unless the body would exceed the limit.
+    ///
+    fn read_body<R: Read>(
+        &self,
+        reader: &mut R,
+        body: &mut Vec<u8>,
+        size: u64,
+    ) -> Result<(), Error> {
+        if body.len() as u64 + size > self.max_response_size as u64 {
+            return Err(Error::ResponseTooLarge(self.max_response_size));
+        }
+        let start = body.len();
+        body.resize(start + size as usize, 0);
+        reader
+            .read_exact(&mut body[start..])
+            .map_err(Error::Connection)
+    }
+
+    ///
+    /// Reads a line of the response head without the line break.
+    ///
+    fn read_line<R: BufRead>(reader: &mut R) -> Result<String, Error> {
+        let mut line = String::new();
+        reader.read_line(&mut line).map_err(Error::Connection)?;
+        if !line.ends_with('\n') {
+            return Err(Error::Connection(io::ErrorKind::UnexpectedEof.into()));
+        }
+        Ok(line.trim_end_matches(&['\r', '\n'][..]).to_owned())
+    }
+
+    ///
+    /// The malformed response error.
+    ///
+    fn invalid_data(message: String) -> Error {
+        Error::Connection(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("malformed response {}", message),
+        ))
+    }
+}
+
+impl Transport for ResolvingTransport {
+    fn send(&self, request: TransportRequest) -> Result<TransportResponse, Error> {
+        let host = request.url.host_str().unwrap_or_default().to_owned();
+        let address = match self.addresses.get(host.as_str()) {
+            Some(address) => *address,
+            None => return self.inner.send(request),
+        };
+
+        let stream =
+            TcpStream::connect_timeout(&address, Self::TIMEOUT).map_err(Error::Connection)?;
+        stream
+            .set_read_timeout(Some(Self::TIMEOUT))
+            .and_then(|()| stream.set_write_timeout(Some(Self::TIMEOUT)))
+            .map_err(Error::Connection)?;
+
+        if request.url.scheme() == "https" {
+            let stream = self
+                .tls
+                .connect(host.as_str(), stream)
+                .map_err(|error| Error::Connection(io::Error::other(error.to_string())))?;
+            self.exchange(stream, request)
+        } else {
+            self.exchange(stream, request)
+        }
+    }
+}
+
+impl Write for LimitedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.body.len() + buf.len() > self.limit {
+            self.is_exceeded = true;
+            return Err(io::Error::other("response size limit exceeded"));
+        }
+        self.body.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::io::BufRead;
+    use std::io::BufReader;
+    use std::io::Write;
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    use reqwest::Method;
+    use reqwest::StatusCode;
+    use reqwest::Url;
+
+    use crate::error::Error;
+
+    use super::ReqwestTransport;
+    use super::ResolvingTransport;
+    use super::Transport;
+    use super::TransportRequest;
+
+    ///
+    /// Pins `api.binance.com` to a local server, which replies with `response` once and sends
+    /// the request head it has received.
+    ///
+    fn serve(response: &'static str) -> (ResolvingTransport, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Local listener");
+        let mut addresses = HashMap::new();
+        addresses.insert(
+            "api.binance.com".to_owned(),
+            listener.local_addr().expect("Local address"),
+        );
+
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().expect("Local connection");
+            let mut reader = BufReader::new(stream);
+            let mut head = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).expect("Request line");
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+                head.push_str(line.as_str());
+            }
+            reader
+                .get_mut()
+                .write_all(response.as_bytes())
+                .expect("Response writing");
+            sender.send(head).expect("Request head");
+        });
+
+        let transport = ResolvingTransport::new(
+            ReqwestTransport::new(reqwest::Client::new()),
+            addresses,
+            native_tls::TlsConnector::new().expect("TLS connector"),
+        );
+        (transport, receiver)
+    }
+
+    fn request() -> TransportRequest {
+        TransportRequest {
+            method: Method::GET,
+            url: Url::parse("http://api.binance.com/api/v3/time?symbol=BTCUSDT")
+                .expect("Valid URL"),
+            api_key: Some("api_key".to_owned()),
+        }
+    }
+
+    #[test]
+    fn pinned_host_is_connected() {
+        let (transport, receiver) = serve(
+            "HTTP/1.1 200 OK\r\n\
+             Content-Type: application/json;charset=UTF-8\r\n\
+             X-MBX-USED-WEIGHT-1M: 1\r\n\
+             Transfer-Encoding: chunked\r\n\
+             \r\n\
+             e\r\n{\"serverTime\":\r\ne\r\n1499827319559}\r\n0\r\n\r\n",
+        );
+
+        let response = transport.send(request()).expect("Pinned response");
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(
+            response.content_type.as_deref(),
+            Some("application/json;charset=UTF-8")
+        );
+        assert_eq!(response.used_weight.as_deref(), Some("1"));
+        assert_eq!(response.body, r#"{"serverTime":1499827319559}"#);
+
+        let head = receiver.recv().expect("Request head");
+        assert!(head.starts_with("GET /api/v3/time?symbol=BTCUSDT HTTP/1.1\r\n"));
+        assert!(head.contains("Host: api.binance.com\r\n"));
+        assert!(head.contains("X-MBX-APIKEY: api_key\r\n"));
+    }
+
+    #[test]
+    fn pinned_response_over_limit_is_rejected() {
+        let (transport, _receiver) = serve(
+            "HTTP/1.1 200 OK\r\n\
+             Content-Length: 28\r\n\
+             \r\n\
+             {\"serverTime\":1499827319559}",
+        );
+
+        match transport.max_response_size(16).send(request()) {
+            Err(Error::ResponseTooLarge(16)) => {}
+            result => panic!("expected ResponseTooLarge, got {:?}", result),
+        }
+    }
+}
@@ -24,8 +24,9 @@ impl Query {
 
 impl ToString for Query {
     fn to_string(&self) -> String {
-        let mut params = String::with_capacity(Self::QUERY_INITIAL_CAPACITY);
-        params += &format!("includeEtf={}", self.include_etf.to_string());
-        params
+        let mut params =
+            form_urlencoded::Serializer::new(String::with_capacity(Self::QUERY_INITIAL_CAPACITY));
+        params.append_pair("includeEtf", &self.include_etf.to_string());
+        params.finish()
     }
 }
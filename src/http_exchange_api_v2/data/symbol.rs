@@ -5,6 +5,7 @@
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
+use crate::http_api_v3::data::serde::deserialize_decimal;
 use crate::http_exchange_api_v2::data::status::Status;
 
 ///
@@ -26,10 +27,10 @@ pub struct Symbol {
     #[serde(rename = "q")]
     pub quote_asset: String,
     /// The price tick size.
-    #[serde(rename = "ts")]
+    #[serde(rename = "ts", deserialize_with = "deserialize_decimal")]
     pub price_tick: Decimal,
     /// The quantity tick size.
-    #[serde(rename = "i")]
+    #[serde(rename = "i", deserialize_with = "deserialize_decimal")]
     pub quantity_tick: Decimal,
     /// The market open timestamp, if the symbol is not being traded yet.
     #[serde(rename = "planToOpenMarketTime")]
@@ -9,35 +9,120 @@ pub(crate) mod http_exchange_api_v2;
 pub(crate) mod websocket;
 
 pub use self::error::Error;
+pub use self::http_api_v3::authorization::sign_query;
+pub use self::http_api_v3::builder::Builder as HttpApiV3ClientBuilder;
+pub use self::http_api_v3::data::account::balance::Balance as AccountBalance;
 pub use self::http_api_v3::data::account::get::request::Query as AccountGetQuery;
 pub use self::http_api_v3::data::account::get::response::Response as AccountGetResponse;
+pub use self::http_api_v3::data::account_commission::get::request::Query as AccountCommissionGetQuery;
+pub use self::http_api_v3::data::account_commission::get::response::Commission as AccountCommission;
+pub use self::http_api_v3::data::account_commission::get::response::Discount as AccountCommissionDiscount;
+pub use self::http_api_v3::data::account_commission::get::response::Response as AccountCommissionGetResponse;
+pub use self::http_api_v3::data::agg_trades::get::request::Query as AggTradesGetQuery;
+pub use self::http_api_v3::data::agg_trades::get::response::AggTrade;
+pub use self::http_api_v3::data::agg_trades::get::response::Response as AggTradesGetResponse;
+pub use self::http_api_v3::data::all_orders::get::request::Query as AllOrdersGetQuery;
+pub use self::http_api_v3::data::all_orders::get::response::Response as AllOrdersGetResponse;
+pub use self::http_api_v3::data::book_ticker::get::request::Query as BookTickerGetQuery;
+pub use self::http_api_v3::data::book_ticker::get::response::BookTicker;
+pub use self::http_api_v3::data::book_ticker::get::response::Response as BookTickerGetResponse;
 pub use self::http_api_v3::data::depth::get::request::Query as DepthGetQuery;
 pub use self::http_api_v3::data::depth::get::response::Response as DepthGetResponse;
 pub use self::http_api_v3::data::depth_element::DepthElement;
+pub use self::http_api_v3::data::error::Error as ResponseError;
+pub use self::http_api_v3::data::exchange_info::filter::Filter as ExchangeInfoFilter;
 pub use self::http_api_v3::data::exchange_info::get::response::Response as ExchangeInfoGetResponse;
+pub use self::http_api_v3::data::exchange_info::symbol::filter::Filter as ExchangeInfoSymbolFilter;
+pub use self::http_api_v3::data::exchange_info::symbol::filter::FilterError as ExchangeInfoSymbolFilterError;
 pub use self::http_api_v3::data::exchange_info::symbol::status::Status as ExchangeInfoSymbolStatus;
 pub use self::http_api_v3::data::exchange_info::symbol::Symbol as ExchangeInfoSymbol;
+pub use self::http_api_v3::data::historical_trades::get::request::Query as HistoricalTradesGetQuery;
+pub use self::http_api_v3::data::historical_trades::get::response::Response as HistoricalTradesGetResponse;
 pub use self::http_api_v3::data::interval::Interval;
+pub use self::http_api_v3::data::klines::candles::Candles;
+pub use self::http_api_v3::data::klines::candles::IntoColumns as KlinesIntoColumns;
 pub use self::http_api_v3::data::klines::get::request::Query as KlinesGetQuery;
 pub use self::http_api_v3::data::klines::get::response::Response as KlinesGetResponse;
 pub use self::http_api_v3::data::klines::kline::Kline;
+pub use self::http_api_v3::data::my_prevented_matches::get::request::Query as MyPreventedMatchesGetQuery;
+pub use self::http_api_v3::data::my_prevented_matches::get::response::PreventedMatch;
+pub use self::http_api_v3::data::my_prevented_matches::get::response::Response as MyPreventedMatchesGetResponse;
+pub use self::http_api_v3::data::my_trades::get::request::Query as MyTradesGetQuery;
+pub use self::http_api_v3::data::my_trades::get::response::Response as MyTradesGetResponse;
+pub use self::http_api_v3::data::my_trades::get::response::Trade as AccountTrade;
+pub use self::http_api_v3::data::open_order_list::get::request::Query as OpenOrderListGetQuery;
+pub use self::http_api_v3::data::open_order_list::get::response::Response as OpenOrderListGetResponse;
 pub use self::http_api_v3::data::open_orders::delete::request::Query as OpenOrdersDeleteQuery;
-pub use self::http_api_v3::data::open_orders::delete::response::OpenOrder as OpenOrdersDeleteResponseElement;
+pub use self::http_api_v3::data::open_orders::delete::response::Report as OpenOrdersDeleteResponseElement;
 pub use self::http_api_v3::data::open_orders::delete::response::Response as OpenOrdersDeleteResponse;
 pub use self::http_api_v3::data::open_orders::get::request::Query as OpenOrdersGetQuery;
-pub use self::http_api_v3::data::open_orders::get::response::OpenOrder as OpenOrdersGetResponseElement;
 pub use self::http_api_v3::data::open_orders::get::response::Response as OpenOrdersGetResponse;
 pub use self::http_api_v3::data::order::delete::request::Query as OrderDeleteQuery;
 pub use self::http_api_v3::data::order::delete::response::Response as OrderDeleteResponse;
 pub use self::http_api_v3::data::order::get::request::Query as OrderGetQuery;
 pub use self::http_api_v3::data::order::get::response::Response as OrderGetResponse;
 pub use self::http_api_v3::data::order::post::request::Query as OrderPostQuery;
+pub use self::http_api_v3::data::order::post::response::idempotent::Idempotent as OrderPostIdempotentResponse;
 pub use self::http_api_v3::data::order::post::response::Response as OrderPostResponse;
+pub use self::http_api_v3::data::order::Order as OpenOrdersGetResponseElement;
+pub use self::http_api_v3::data::order::Order;
+pub use self::http_api_v3::data::order_amend_keep_priority::put::request::Query as OrderAmendKeepPriorityPutQuery;
+pub use self::http_api_v3::data::order_amend_keep_priority::put::response::AmendedOrder as OrderAmendKeepPriorityPutResponseOrder;
+pub use self::http_api_v3::data::order_amend_keep_priority::put::response::Response as OrderAmendKeepPriorityPutResponse;
+pub use self::http_api_v3::data::order_list::contingency_type::ContingencyType as OrderListContingencyType;
+pub use self::http_api_v3::data::order_list::delete::request::Query as OrderListDeleteQuery;
+pub use self::http_api_v3::data::order_list::delete::response::Response as OrderListDeleteResponse;
+pub use self::http_api_v3::data::order_list::get::request::Query as OrderListGetQuery;
+pub use self::http_api_v3::data::order_list::get::response::Response as OrderListGetResponse;
+pub use self::http_api_v3::data::order_list::list_order_status::ListOrderStatus as OrderListOrderStatus;
+pub use self::http_api_v3::data::order_list::list_status_type::ListStatusType as OrderListStatusType;
+pub use self::http_api_v3::data::order_list::ListOrder as OrderListOrder;
+pub use self::http_api_v3::data::order_list::OrderList;
 pub use self::http_api_v3::data::order_side::OrderSide;
 pub use self::http_api_v3::data::order_status::OrderStatus;
 pub use self::http_api_v3::data::order_type::OrderType;
+pub use self::http_api_v3::data::permission::Permission;
+pub use self::http_api_v3::data::price_ticker::get::request::Query as PriceTickerGetQuery;
+pub use self::http_api_v3::data::price_ticker::get::response::PriceTicker;
+pub use self::http_api_v3::data::price_ticker::get::response::Response as PriceTickerGetResponse;
+pub use self::http_api_v3::data::rate_limit::interval::RateLimitInterval;
+pub use self::http_api_v3::data::rate_limit::r#type::RateLimitType;
+pub use self::http_api_v3::data::rate_limit::RateLimit;
+pub use self::http_api_v3::data::rate_limit_order::get::request::Query as RateLimitOrderGetQuery;
+pub use self::http_api_v3::data::rate_limit_order::get::response::Response as RateLimitOrderGetResponse;
+pub use self::http_api_v3::data::sapi::deposit_history::get::request::Query as DepositHistoryGetQuery;
+pub use self::http_api_v3::data::sapi::deposit_history::get::response::Deposit;
+pub use self::http_api_v3::data::sapi::deposit_history::get::response::Response as DepositHistoryGetResponse;
+pub use self::http_api_v3::data::sapi::system_status::get::response::Response as SystemStatusGetResponse;
+pub use self::http_api_v3::data::sapi::system_status::status::Status as SystemStatus;
+pub use self::http_api_v3::data::sapi::withdraw_history::get::request::Query as WithdrawHistoryGetQuery;
+pub use self::http_api_v3::data::sapi::withdraw_history::get::response::Response as WithdrawHistoryGetResponse;
+pub use self::http_api_v3::data::sapi::withdraw_history::get::response::Withdrawal;
+pub use self::http_api_v3::data::self_trade_prevention_mode::SelfTradePreventionMode;
+pub use self::http_api_v3::data::serde::PriceType;
+pub use self::http_api_v3::data::ticker_24hr::get::request::Query as Ticker24hrGetQuery;
+pub use self::http_api_v3::data::ticker_24hr::get::response::Response as Ticker24hrGetResponse;
+pub use self::http_api_v3::data::ticker_24hr::get::response::Ticker as Ticker24hr;
+pub use self::http_api_v3::data::ticker_rolling::get::request::Query as TickerRollingGetQuery;
+pub use self::http_api_v3::data::ticker_rolling::get::response::Response as TickerRollingGetResponse;
+pub use self::http_api_v3::data::ticker_rolling::get::response::RollingTicker;
+pub use self::http_api_v3::data::ticker_type::TickerType;
 pub use self::http_api_v3::data::time::get::response::Response as TimeGetResponse;
+pub use self::http_api_v3::data::trades::get::request::Query as TradesGetQuery;
+pub use self::http_api_v3::data::trades::get::response::Response as TradesGetResponse;
+pub use self::http_api_v3::data::trades::get::response::Trade as MarketTrade;
+pub use self::http_api_v3::data::user_data_stream::post::response::Response as UserDataStreamPostResponse;
+pub use self::http_api_v3::rate_limiter::Mode as HttpApiV3RateLimiterMode;
 pub use self::http_api_v3::response::Response as HttpApiV3Response;
+pub use self::http_api_v3::response::ResponseWithRaw as HttpApiV3ResponseWithRaw;
+pub use self::http_api_v3::retry_policy::RetryPolicy as HttpApiV3RetryPolicy;
+pub use self::http_api_v3::signed_query::SignedQuery as HttpApiV3SignedQuery;
+pub use self::http_api_v3::time_sync::TimeSync as HttpApiV3TimeSync;
+pub use self::http_api_v3::transport::ReqwestTransport as HttpApiV3ReqwestTransport;
+pub use self::http_api_v3::transport::ResolvingTransport as HttpApiV3ResolvingTransport;
+pub use self::http_api_v3::transport::Transport as HttpApiV3Transport;
+pub use self::http_api_v3::transport::TransportRequest as HttpApiV3TransportRequest;
+pub use self::http_api_v3::transport::TransportResponse as HttpApiV3TransportResponse;
 pub use self::http_api_v3::Client as HttpApiV3Client;
 pub use self::http_exchange_api_v2::data::product_by_symbol::get::request::Query as ProductBySymbolGetQuery;
 pub use self::http_exchange_api_v2::data::product_by_symbol::get::response::Response as ProductBySymbolGetResponse;
@@ -47,7 +132,20 @@ pub use self::http_exchange_api_v2::data::status::Status as ProductSymbolStatus;
 pub use self::http_exchange_api_v2::data::symbol::Symbol as ProductSymbol;
 pub use self::http_exchange_api_v2::response::Response as HttpExchangeApiV2Response;
 pub use self::http_exchange_api_v2::Client as HttpExchangeApiV2Client;
+pub use self::websocket::candle_builder::CandleBuilder as WebSocketCandleBuilder;
+pub use self::websocket::combined::CombinedEvent as WebSocketCombinedEvent;
+pub use self::websocket::combined::CombinedStream as WebSocketCombinedStream;
+pub use self::websocket::event::account_position::AccountPosition as WebSocketAccountPositionEvent;
+pub use self::websocket::event::account_position::PositionBalance as WebSocketPositionBalance;
+pub use self::websocket::event::balance_update::BalanceUpdate as WebSocketBalanceUpdateEvent;
 pub use self::websocket::event::depth::Depth as WebSocketDepthEvent;
+pub use self::websocket::event::execution_report::ExecutionReport as WebSocketExecutionReportEvent;
+pub use self::websocket::event::execution_report::ExecutionType as WebSocketExecutionType;
+pub use self::websocket::event::kline::KlineEvent as WebSocketKlineEvent;
 pub use self::websocket::event::trade::Trade as WebSocketTradeEvent;
+pub use self::websocket::event::user_data::UserDataEvent as WebSocketUserDataEvent;
 pub use self::websocket::event::Event as WebSocketEvent;
+pub use self::websocket::order_book::LocalOrderBook as WebSocketLocalOrderBook;
+pub use self::websocket::user_data::Keepalive as WebSocketUserDataKeepalive;
+pub use self::websocket::user_data::UserDataStream as WebSocketUserDataStream;
 pub use self::websocket::Client as WebSocketClient;
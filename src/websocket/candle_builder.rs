@@ -0,0 +1,234 @@
+//!
+//! The candle builder, which aggregates the WebSocket trades into klines.
+//!
+
+use crate::http_api_v3::data::interval::Interval;
+use crate::http_api_v3::data::klines::kline::Kline;
+use crate::http_api_v3::data::serde::PriceType;
+
+use super::event::trade::Trade;
+
+///
+/// The candle builder, which aggregates the trades of a single symbol into klines aligned to the
+/// interval boundaries since Unix epoch.
+///
+/// The intervals without trades are emitted as flat candles with the previous close price and
+/// zero volume. The month interval is treated as 30 days.
+///
+#[derive(Debug, Clone)]
+pub struct CandleBuilder {
+    /// The candle duration in milliseconds.
+    interval_ms: i64,
+    /// The candle being built.
+    current: Option<Kline>,
+}
+
+impl CandleBuilder {
+    ///
+    /// Creates a builder of the `interval` candles.
+    ///
+    pub fn new(interval: Interval) -> Self {
+        let interval_s: i64 = interval.into();
+        Self {
+            interval_ms: interval_s * 1000,
+            current: None,
+        }
+    }
+
+    ///
+    /// Adds the trade to the current candle and returns the candles completed before it, if the
+    /// trade starts a new one.
+    ///
+    /// The trades are expected in order, so a trade older than the current candle is added to it.
+    ///
+    pub fn push(&mut self, trade: &Trade) -> Vec<Kline> {
+        let completed = self.close_until(trade.trade_time);
+
+        let interval_ms = self.interval_ms;
+        let candle = self.current.get_or_insert_with(|| {
+            let open_time = trade.trade_time - trade.trade_time.rem_euclid(interval_ms);
+            Self::flat(open_time, interval_ms, trade.price)
+        });
+        if candle.number_of_trades == 0 {
+            candle.open = trade.price;
+            candle.high = trade.price;
+            candle.low = trade.price;
+        }
+        if trade.price > candle.high {
+            candle.high = trade.price;
+        }
+        if trade.price < candle.low {
+            candle.low = trade.price;
+        }
+        candle.close = trade.price;
+        candle.volume += trade.quantity;
+        candle.quote_asset_volume += trade.price * trade.quantity;
+        if !trade.is_market_maker {
+            candle.taker_buy_base_asset_volume += trade.quantity;
+            candle.taker_buy_quote_asset_volume += trade.price * trade.quantity;
+        }
+        candle.number_of_trades += 1;
+
+        completed
+    }
+
+    ///
+    /// Returns the candles, which have ended by `time` in milliseconds since Unix epoch, e.g. to
+    /// emit the candles by the clock while there are no trades.
+    ///
+    pub fn close_until(&mut self, time: i64) -> Vec<Kline> {
+        let mut completed = Vec::new();
+        while let Some(candle) = self.current.take() {
+            if time <= candle.close_time {
+                self.current = Some(candle);
+                break;
+            }
+            self.current = Some(Self::flat(
+                candle.close_time + 1,
+                self.interval_ms,
+                candle.close,
+            ));
+            completed.push(candle);
+        }
+        completed
+    }
+
+    ///
+    /// The candle being built, if any trade has been received.
+    ///
+    pub fn current(&self) -> Option<&Kline> {
+        self.current.as_ref()
+    }
+
+    ///
+    /// Creates a flat candle without trades with all the prices equal to `price`.
+    ///
+    fn flat(open_time: i64, interval_ms: i64, price: PriceType) -> Kline {
+        Kline {
+            open_time,
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume: PriceType::default(),
+            close_time: open_time + interval_ms - 1,
+            quote_asset_volume: PriceType::default(),
+            number_of_trades: 0,
+            taker_buy_base_asset_volume: PriceType::default(),
+            taker_buy_quote_asset_volume: PriceType::default(),
+            ignore: PriceType::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http_api_v3::data::interval::Interval;
+    use crate::http_api_v3::data::serde::PriceType;
+    use crate::websocket::event::trade::Trade;
+
+    use super::CandleBuilder;
+
+    /// The minute interval in milliseconds.
+    const MINUTE_MS: i64 = 60_000;
+
+    fn price(value: &str) -> PriceType {
+        value.parse().expect("Valid price")
+    }
+
+    fn trade(trade_time: i64, price: &str, quantity: &str, is_market_maker: bool) -> Trade {
+        serde_json::from_value(serde_json::json!({
+            "e": "trade",
+            "E": trade_time,
+            "s": "BTCUSDT",
+            "t": 1,
+            "p": price,
+            "q": quantity,
+            "b": 1,
+            "a": 2,
+            "T": trade_time,
+            "m": is_market_maker,
+            "M": true,
+        }))
+        .expect("Valid trade")
+    }
+
+    #[test]
+    fn candle_is_aligned_to_interval_boundary() {
+        let mut builder = CandleBuilder::new(Interval::Minute1);
+        assert!(builder
+            .push(&trade(10 * MINUTE_MS + 12_345, "10", "1", false))
+            .is_empty());
+
+        let candle = builder.current().expect("Current candle");
+        assert_eq!(candle.open_time, 10 * MINUTE_MS);
+        assert_eq!(candle.close_time, 11 * MINUTE_MS - 1);
+    }
+
+    #[test]
+    fn candle_aggregates_prices_and_volumes() {
+        let mut builder = CandleBuilder::new(Interval::Minute1);
+        builder.push(&trade(MINUTE_MS, "10", "1", false));
+        builder.push(&trade(MINUTE_MS + 1, "12", "2", true));
+        builder.push(&trade(MINUTE_MS + 2, "8", "0.5", false));
+        builder.push(&trade(MINUTE_MS + 3, "11", "1", true));
+
+        let candle = builder.current().expect("Current candle");
+        assert_eq!(candle.open, price("10"));
+        assert_eq!(candle.high, price("12"));
+        assert_eq!(candle.low, price("8"));
+        assert_eq!(candle.close, price("11"));
+        assert_eq!(candle.volume, price("4.5"));
+        assert_eq!(candle.quote_asset_volume, price("49"));
+        assert_eq!(candle.taker_buy_base_asset_volume, price("1.5"));
+        assert_eq!(candle.taker_buy_quote_asset_volume, price("14"));
+        assert_eq!(candle.number_of_trades, 4);
+    }
+
+    #[test]
+    fn empty_interval_is_flat_at_previous_close() {
+        let mut builder = CandleBuilder::new(Interval::Minute1);
+        builder.push(&trade(MINUTE_MS, "10", "1", false));
+        builder.push(&trade(MINUTE_MS + 1, "11", "1", false));
+
+        let completed = builder.push(&trade(3 * MINUTE_MS + 1, "12", "1", false));
+        assert_eq!(completed.len(), 2);
+        assert_eq!(completed[0].open_time, MINUTE_MS);
+        assert_eq!(completed[0].close, price("11"));
+
+        let flat = &completed[1];
+        assert_eq!(flat.open_time, 2 * MINUTE_MS);
+        assert_eq!(flat.open, price("11"));
+        assert_eq!(flat.high, price("11"));
+        assert_eq!(flat.low, price("11"));
+        assert_eq!(flat.close, price("11"));
+        assert_eq!(flat.volume, PriceType::default());
+        assert_eq!(flat.number_of_trades, 0);
+
+        let current = builder.current().expect("Current candle");
+        assert_eq!(current.open_time, 3 * MINUTE_MS);
+        assert_eq!(current.open, price("12"));
+    }
+
+    #[test]
+    fn close_until_emits_candles_without_trades() {
+        let mut builder = CandleBuilder::new(Interval::Minute1);
+        assert!(builder.close_until(10 * MINUTE_MS).is_empty());
+
+        builder.push(&trade(MINUTE_MS, "10", "1", false));
+        assert!(builder.close_until(2 * MINUTE_MS - 1).is_empty());
+
+        let completed = builder.close_until(4 * MINUTE_MS);
+        assert_eq!(completed.len(), 3);
+        assert_eq!(completed[0].number_of_trades, 1);
+        for (index, candle) in completed.iter().enumerate().skip(1) {
+            assert_eq!(candle.open_time, (index as i64 + 1) * MINUTE_MS);
+            assert_eq!(candle.close, price("10"));
+            assert_eq!(candle.number_of_trades, 0);
+        }
+        assert_eq!(
+            builder.current().expect("Current candle").open_time,
+            4 * MINUTE_MS
+        );
+    }
+}
@@ -0,0 +1,382 @@
+//!
+//! The Binance WebSocket combined stream.
+//!
+
+use std::io;
+use std::io::Read;
+use std::io::Write;
+use std::net::TcpStream;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use native_tls::TlsConnector;
+use native_tls::TlsStream;
+use serde::Deserialize;
+use websocket::client::ClientBuilder;
+use websocket::stream::sync::ReadWritePair;
+use websocket::OwnedMessage;
+use websocket::WebSocketError;
+
+use crate::error::Error;
+
+use super::event::depth::Depth;
+use super::event::kline::KlineEvent;
+use super::event::trade::Trade;
+use super::event::Event;
+
+///
+/// The event received via the combined stream, along with the name of its stream.
+///
+#[derive(Debug, Clone)]
+pub struct CombinedEvent {
+    /// The stream name, e.g. `btcusdt@trade`.
+    pub stream: String,
+    /// The stream event.
+    pub event: Event,
+}
+
+///
+/// The Binance WebSocket combined stream, which multiplexes several streams over one connection.
+///
+/// Only the `trade`, `depth`, and `kline_<interval>` streams are parsed, and the other events are
+/// skipped.
+///
+#[derive(Debug)]
+pub struct CombinedStream {
+    /// The writer command sender.
+    control: mpsc::Sender<Control>,
+    /// The demultiplexed event receiver.
+    events: mpsc::Receiver<CombinedEvent>,
+}
+
+///
+/// The writer command.
+///
+#[derive(Debug)]
+enum Control {
+    /// Adds the streams to the connection.
+    Subscribe(Vec<String>),
+    /// Removes the streams from the connection.
+    Unsubscribe(Vec<String>),
+    /// Replies to the server ping with its payload.
+    Pong(Vec<u8>),
+}
+
+///
+/// The combined stream message envelope.
+///
+#[derive(Debug, Deserialize)]
+struct Envelope {
+    /// The stream name.
+    stream: String,
+    /// The stream event data.
+    data: serde_json::Value,
+}
+
+///
+/// The TLS stream shared by the reader and the writer threads, since it cannot be split.
+///
+#[derive(Debug)]
+struct SharedStream {
+    /// The stream, which is locked for each read or write attempt.
+    inner: Mutex<TlsStream<TcpStream>>,
+    /// The number of the writes waiting for the lock, which the reader yields to.
+    pending_writes: AtomicUsize,
+}
+
+///
+/// The reading half of the shared stream.
+///
+/// The socket read timeouts are retried here, so a frame is never cut in the middle.
+///
+#[derive(Debug)]
+struct SharedReader(Arc<SharedStream>);
+
+///
+/// The writing half of the shared stream.
+///
+#[derive(Debug)]
+struct SharedWriter(Arc<SharedStream>);
+
+impl CombinedStream {
+    /// The combined stream host.
+    const HOST: &'static str = "stream.binance.com";
+    /// The combined stream port.
+    const PORT: u16 = 9443;
+    /// The socket read timeout, after which the reader releases the stream to the writer.
+    const READ_TIMEOUT: Duration = Duration::from_millis(100);
+
+    ///
+    /// Connects to the combined stream of `streams`, e.g. `btcusdt@trade` and
+    /// `ethusdt@depth@100ms`.
+    ///
+    pub fn connect(streams: Vec<String>) -> Result<Self, Error> {
+        let address = format!(
+            "wss://{}:{}/stream?streams={}",
+            Self::HOST,
+            Self::PORT,
+            streams.join("/")
+        );
+
+        let socket = TcpStream::connect((Self::HOST, Self::PORT))
+            .map_err(|error| Error::WebSocket(error.into()))?;
+        let tls = TlsConnector::new()
+            .map_err(|error| Error::WebSocket(WebSocketError::Other(Box::new(error))))?
+            .connect(Self::HOST, socket)
+            .map_err(|error| Error::WebSocket(WebSocketError::Other(Box::new(error))))?;
+        tls.get_ref()
+            .set_read_timeout(Some(Self::READ_TIMEOUT))
+            .map_err(|error| Error::WebSocket(error.into()))?;
+
+        let shared = Arc::new(SharedStream {
+            inner: Mutex::new(tls),
+            pending_writes: AtomicUsize::new(0),
+        });
+        let stream = ReadWritePair(SharedReader(shared.clone()), SharedWriter(shared));
+        let (mut reader, mut writer) = ClientBuilder::new(&address)
+            .expect("WebSocket address is valid")
+            .connect_on(stream)
+            .map_err(Error::WebSocket)?
+            .split()
+            .map_err(|error| Error::WebSocket(error.into()))?;
+
+        let (control_tx, control_rx) = mpsc::channel();
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let mut request_id = 0;
+            for control in control_rx.iter() {
+                let message = match control {
+                    Control::Subscribe(streams) => {
+                        request_id += 1;
+                        Self::control_request("SUBSCRIBE", streams, request_id)
+                    }
+                    Control::Unsubscribe(streams) => {
+                        request_id += 1;
+                        Self::control_request("UNSUBSCRIBE", streams, request_id)
+                    }
+                    Control::Pong(payload) => OwnedMessage::Pong(payload),
+                };
+                if let Err(error) = writer.send_message(&message) {
+                    log::error!("Websocket error: {}", error);
+                    return;
+                }
+            }
+        });
+
+        let pong_tx = control_tx.clone();
+        thread::spawn(move || loop {
+            let message = match reader.recv_message() {
+                Ok(OwnedMessage::Text(message)) => message,
+                Ok(OwnedMessage::Ping(payload)) => {
+                    log::debug!("Received ping");
+                    if pong_tx.send(Control::Pong(payload)).is_err() {
+                        return;
+                    }
+                    continue;
+                }
+                Ok(OwnedMessage::Close(_)) => {
+                    log::debug!("Websocket closed");
+                    return;
+                }
+                Ok(_) => continue,
+                Err(error) => {
+                    log::error!("Websocket error: {}", error);
+                    return;
+                }
+            };
+
+            if let Some(event) = Self::demux(message.as_str()) {
+                if tx.send(event).is_err() {
+                    return;
+                }
+            }
+        });
+
+        Ok(Self {
+            control: control_tx,
+            events: rx,
+        })
+    }
+
+    ///
+    /// Adds the streams to the connection.
+    ///
+    pub fn subscribe(&self, streams: Vec<String>) -> Result<(), Error> {
+        self.control
+            .send(Control::Subscribe(streams))
+            .map_err(|_| Error::WebSocketClosed)
+    }
+
+    ///
+    /// Removes the streams from the connection.
+    ///
+    pub fn unsubscribe(&self, streams: Vec<String>) -> Result<(), Error> {
+        self.control
+            .send(Control::Unsubscribe(streams))
+            .map_err(|_| Error::WebSocketClosed)
+    }
+
+    ///
+    /// The demultiplexed event receiver, which is disconnected when the connection is closed.
+    ///
+    pub fn events(&self) -> &mpsc::Receiver<CombinedEvent> {
+        &self.events
+    }
+
+    ///
+    /// The subscription control request with the `method`, e.g. `SUBSCRIBE`.
+    ///
+    fn control_request(method: &str, streams: Vec<String>, request_id: u64) -> OwnedMessage {
+        let request = serde_json::json!({
+            "method": method,
+            "params": streams,
+            "id": request_id,
+        });
+        OwnedMessage::Text(request.to_string())
+    }
+
+    ///
+    /// Parses the event from the combined stream `message`.
+    ///
+    /// Returns `None` for the control responses, the unsupported streams, and the malformed
+    /// events, which are logged.
+    ///
+    fn demux(message: &str) -> Option<CombinedEvent> {
+        let envelope = match serde_json::from_str::<Envelope>(message) {
+            Ok(envelope) => envelope,
+            Err(_) => {
+                log::debug!("Received control response: {}", message);
+                return None;
+            }
+        };
+
+        let event = if envelope.stream.ends_with("@trade") {
+            serde_json::from_value::<Trade>(envelope.data).map(Event::Trade)
+        } else if envelope.stream.contains("@depth") {
+            serde_json::from_value::<Depth>(envelope.data).map(Event::Depth)
+        } else if envelope.stream.contains("@kline_") {
+            serde_json::from_value::<KlineEvent>(envelope.data).map(Event::Kline)
+        } else {
+            log::debug!("Skipped the unsupported stream {}", envelope.stream);
+            return None;
+        };
+
+        match event {
+            Ok(event) => Some(CombinedEvent {
+                stream: envelope.stream,
+                event,
+            }),
+            Err(error) => {
+                log::warn!("Parsing error: {} ({:?})", error, message);
+                None
+            }
+        }
+    }
+}
+
+impl Read for SharedReader {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        loop {
+            while self.0.pending_writes.load(Ordering::Acquire) > 0 {
+                thread::yield_now();
+            }
+
+            let result = self
+                .0
+                .inner
+                .lock()
+                .expect("WebSocket stream lock")
+                .read(buffer);
+            match result {
+                Err(ref error)
+                    if error.kind() == io::ErrorKind::WouldBlock
+                        || error.kind() == io::ErrorKind::TimedOut =>
+                {
+                    continue
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl SharedWriter {
+    ///
+    /// Runs `operation` on the stream, making the reader yield the lock to it.
+    ///
+    fn with_stream<T, F>(&mut self, operation: F) -> io::Result<T>
+    where
+        F: FnOnce(&mut TlsStream<TcpStream>) -> io::Result<T>,
+    {
+        self.0.pending_writes.fetch_add(1, Ordering::AcqRel);
+        let result = operation(&mut self.0.inner.lock().expect("WebSocket stream lock"));
+        self.0.pending_writes.fetch_sub(1, Ordering::AcqRel);
+        result
+    }
+}
+
+impl Write for SharedWriter {
+    fn write(&mut self, buffer: &[u8]) -> io::Result<usize> {
+        self.with_stream(|stream| stream.write(buffer))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.with_stream(|stream| stream.flush())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::websocket::event::Event;
+
+    use super::CombinedStream;
+
+    #[test]
+    fn trade_envelope_is_demultiplexed() {
+        let message = r#"{
+            "stream": "bnbbtc@trade",
+            "data": {
+                "e": "trade",
+                "E": 123456789,
+                "s": "BNBBTC",
+                "t": 12345,
+                "p": "0.001",
+                "q": "100",
+                "b": 88,
+                "a": 50,
+                "T": 123456785,
+                "m": true,
+                "M": true
+            }
+        }"#;
+
+        let event = CombinedStream::demux(message).expect("Trade event");
+        assert_eq!(event.stream, "bnbbtc@trade");
+        match event.event {
+            Event::Trade(trade) => {
+                assert_eq!(trade.symbol, "BNBBTC");
+                assert_eq!(trade.trade_id, 12345);
+                assert_eq!(trade.buyer_order_id, 88);
+                assert_eq!(trade.seller_order_id, 50);
+            }
+            event => panic!("expected a trade, got {:?}", event),
+        }
+    }
+
+    #[test]
+    fn control_response_is_skipped() {
+        assert!(CombinedStream::demux(r#"{"result":null,"id":1}"#).is_none());
+    }
+
+    #[test]
+    fn unsupported_stream_is_skipped() {
+        let message = r#"{"stream":"bnbbtc@bookTicker","data":{"u":400900217}}"#;
+        assert!(CombinedStream::demux(message).is_none());
+    }
+}
@@ -0,0 +1,40 @@
+//!
+//! The account position event data, received via the user data stream.
+//!
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::http_api_v3::data::serde::deserialize_decimal;
+
+///
+/// The `outboundAccountPosition` event data, which is sent on the account balance changes.
+///
+#[derive(Debug, Deserialize, Clone)]
+pub struct AccountPosition {
+    /// The event time in milliseconds since Unix epoch.
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// The account last update time in milliseconds since Unix epoch.
+    #[serde(rename = "u")]
+    pub last_update_time: i64,
+    /// The changed balances.
+    #[serde(rename = "B")]
+    pub balances: Vec<PositionBalance>,
+}
+
+///
+/// The changed account balance.
+///
+#[derive(Debug, Deserialize, Clone)]
+pub struct PositionBalance {
+    /// The token name.
+    #[serde(rename = "a")]
+    pub asset: String,
+    /// The free balance amount.
+    #[serde(rename = "f", deserialize_with = "deserialize_decimal")]
+    pub free: Decimal,
+    /// The locked balance amount.
+    #[serde(rename = "l", deserialize_with = "deserialize_decimal")]
+    pub locked: Decimal,
+}
@@ -0,0 +1,27 @@
+//!
+//! The balance update event data, received via the user data stream.
+//!
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::http_api_v3::data::serde::deserialize_decimal;
+
+///
+/// The `balanceUpdate` event data, which is sent on deposits, withdrawals, and transfers.
+///
+#[derive(Debug, Deserialize, Clone)]
+pub struct BalanceUpdate {
+    /// The event time in milliseconds since Unix epoch.
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// The token name.
+    #[serde(rename = "a")]
+    pub asset: String,
+    /// The balance delta.
+    #[serde(rename = "d", deserialize_with = "deserialize_decimal")]
+    pub delta: Decimal,
+    /// The clear time in milliseconds since Unix epoch.
+    #[serde(rename = "T")]
+    pub clear_time: i64,
+}
@@ -0,0 +1,141 @@
+//!
+//! The execution report event data, received via the user data stream.
+//!
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::http_api_v3::data::order_side::OrderSide;
+use crate::http_api_v3::data::order_status::OrderStatus;
+use crate::http_api_v3::data::order_time_in_force::OrderTimeInForce;
+use crate::http_api_v3::data::order_type::OrderType;
+use crate::http_api_v3::data::serde::deserialize_decimal;
+
+///
+/// The `executionReport` event data, which is sent on the order updates.
+///
+#[derive(Debug, Deserialize, Clone)]
+pub struct ExecutionReport {
+    /// The event time in milliseconds since Unix epoch.
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// The symbol name.
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// The client-side order ID.
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    /// The order side.
+    #[serde(rename = "S")]
+    pub side: OrderSide,
+    /// The order type.
+    #[serde(rename = "o")]
+    pub r#type: OrderType,
+    /// The order time-in-force.
+    #[serde(rename = "f")]
+    pub time_in_force: OrderTimeInForce,
+    /// The order quantity.
+    #[serde(rename = "q", deserialize_with = "deserialize_decimal")]
+    pub quantity: Decimal,
+    /// The order price.
+    #[serde(rename = "p", deserialize_with = "deserialize_decimal")]
+    pub price: Decimal,
+    /// The stop price.
+    #[serde(rename = "P", deserialize_with = "deserialize_decimal")]
+    pub stop_price: Decimal,
+    /// The iceberg order quantity.
+    #[serde(rename = "F", deserialize_with = "deserialize_decimal")]
+    pub iceberg_quantity: Decimal,
+    /// The server-side order list ID. `-1` if the order is not a part of a list.
+    #[serde(rename = "g")]
+    pub order_list_id: i64,
+    /// The original client-side order ID, which is set for the cancellations.
+    #[serde(rename = "C")]
+    pub orig_client_order_id: String,
+    /// The current execution type.
+    #[serde(rename = "x")]
+    pub execution_type: ExecutionType,
+    /// The current order status.
+    #[serde(rename = "X")]
+    pub status: OrderStatus,
+    /// The order reject reason. `NONE` if the order is not rejected.
+    #[serde(rename = "r")]
+    pub reject_reason: String,
+    /// The server-side order ID.
+    #[serde(rename = "i")]
+    pub order_id: i64,
+    /// The last executed quantity.
+    #[serde(rename = "l", deserialize_with = "deserialize_decimal")]
+    pub last_executed_quantity: Decimal,
+    /// The cumulative filled quantity.
+    #[serde(rename = "z", deserialize_with = "deserialize_decimal")]
+    pub cumulative_filled_quantity: Decimal,
+    /// The last executed price.
+    #[serde(rename = "L", deserialize_with = "deserialize_decimal")]
+    pub last_executed_price: Decimal,
+    /// The commission amount of the last execution.
+    #[serde(rename = "n", deserialize_with = "deserialize_decimal")]
+    pub commission: Decimal,
+    /// The commission token name. `None` if there has been no execution.
+    #[serde(rename = "N")]
+    pub commission_asset: Option<String>,
+    /// The transaction time in milliseconds since Unix epoch.
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+    /// The trade ID. `-1` if the update is not a trade.
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+    /// If the order is on the book.
+    #[serde(rename = "w")]
+    pub is_working: bool,
+    /// If the trade is the maker side.
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+    /// The order creation time in milliseconds since Unix epoch.
+    #[serde(rename = "O")]
+    pub creation_time: i64,
+    /// The cumulative quote token transacted quantity.
+    #[serde(rename = "Z", deserialize_with = "deserialize_decimal")]
+    pub cumulative_quote_quantity: Decimal,
+    /// The last quote token transacted quantity, that is, `last_executed_price *
+    /// last_executed_quantity`.
+    #[serde(rename = "Y", deserialize_with = "deserialize_decimal")]
+    pub last_quote_quantity: Decimal,
+    /// The quote order quantity.
+    #[serde(rename = "Q", deserialize_with = "deserialize_decimal")]
+    pub quote_order_quantity: Decimal,
+}
+
+impl ExecutionReport {
+    ///
+    /// If the update is a trade, that is, the order has been partially or completely filled.
+    ///
+    pub fn is_trade(&self) -> bool {
+        matches!(self.execution_type, ExecutionType::Trade)
+    }
+}
+
+///
+/// The execution report type.
+///
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExecutionType {
+    /// The order has been accepted.
+    New,
+    /// The order has been cancelled.
+    Canceled,
+    /// Currently unused.
+    Replaced,
+    /// The order has been rejected.
+    Rejected,
+    /// The order has been partially or completely filled.
+    Trade,
+    /// The order has been expired.
+    Expired,
+    /// The order has been expired due to the self-trade prevention.
+    TradePrevention,
+    /// Fallback for all other variants.
+    #[serde(other)]
+    Other,
+}
@@ -0,0 +1,198 @@
+//!
+//! The kline event data, received via WebSocket.
+//!
+
+use chrono::prelude::*;
+use serde::Deserialize;
+
+use crate::http_api_v3::data::interval::Interval;
+use crate::http_api_v3::data::klines::kline::Kline;
+use crate::http_api_v3::data::serde::deserialize_price;
+use crate::http_api_v3::data::serde::PriceType;
+
+///
+/// The kline event data, received via WebSocket.
+///
+/// The kline is the same as the REST one, so the WebSocket and REST candles may be treated
+/// uniformly. The kline is updated every second until it is closed.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(from = "KlineEventData")]
+pub struct KlineEvent {
+    /// The kline event type. Usually equal to `kline`.
+    pub event_type: String,
+    /// The kline event time in milliseconds since Unix epoch.
+    pub event_time: i64,
+    /// The trading symbol name.
+    pub symbol: String,
+    /// The kline interval.
+    pub interval: Interval,
+    /// If the kline is closed, so it will not change anymore.
+    pub is_closed: bool,
+    /// The kline data.
+    pub kline: Kline,
+}
+
+///
+/// The kline event data as sent by Binance.
+///
+#[derive(Debug, Deserialize)]
+struct KlineEventData {
+    /// The kline event type.
+    #[serde(rename = "e")]
+    event_type: String,
+    /// The kline event time in milliseconds since Unix epoch.
+    #[serde(rename = "E")]
+    event_time: i64,
+    /// The trading symbol name.
+    #[serde(rename = "s")]
+    symbol: String,
+    /// The kline data.
+    #[serde(rename = "k")]
+    kline: KlineData,
+}
+
+///
+/// The kline data as sent by Binance.
+///
+#[derive(Debug, Deserialize)]
+struct KlineData {
+    /// The kline open time in milliseconds since Unix epoch.
+    #[serde(rename = "t")]
+    open_time: i64,
+    /// The kline close time in milliseconds since Unix epoch.
+    #[serde(rename = "T")]
+    close_time: i64,
+    /// The kline interval.
+    #[serde(rename = "i")]
+    interval: Interval,
+    /// The kline open price.
+    #[serde(rename = "o", deserialize_with = "deserialize_price")]
+    open: PriceType,
+    /// The kline close price.
+    #[serde(rename = "c", deserialize_with = "deserialize_price")]
+    close: PriceType,
+    /// The kline high price.
+    #[serde(rename = "h", deserialize_with = "deserialize_price")]
+    high: PriceType,
+    /// The kline low price.
+    #[serde(rename = "l", deserialize_with = "deserialize_price")]
+    low: PriceType,
+    /// The kline volume in secondary token.
+    #[serde(rename = "v", deserialize_with = "deserialize_price")]
+    volume: PriceType,
+    /// The number of trades executed within the kline.
+    #[serde(rename = "n")]
+    number_of_trades: i64,
+    /// If the kline is closed.
+    #[serde(rename = "x")]
+    is_closed: bool,
+    /// The kline volume in primary token.
+    #[serde(rename = "q", deserialize_with = "deserialize_price")]
+    quote_asset_volume: PriceType,
+    /// The taker buy volume in secondary token.
+    #[serde(rename = "V", deserialize_with = "deserialize_price")]
+    taker_buy_base_asset_volume: PriceType,
+    /// The taker buy volume in primary token.
+    #[serde(rename = "Q", deserialize_with = "deserialize_price")]
+    taker_buy_quote_asset_volume: PriceType,
+}
+
+impl From<KlineEventData> for KlineEvent {
+    fn from(data: KlineEventData) -> Self {
+        let kline = data.kline;
+        Self {
+            event_type: data.event_type,
+            event_time: data.event_time,
+            symbol: data.symbol,
+            interval: kline.interval,
+            is_closed: kline.is_closed,
+            kline: Kline {
+                open_time: kline.open_time,
+                open: kline.open,
+                high: kline.high,
+                low: kline.low,
+                close: kline.close,
+                volume: kline.volume,
+                close_time: kline.close_time,
+                quote_asset_volume: kline.quote_asset_volume,
+                number_of_trades: kline.number_of_trades,
+                taker_buy_base_asset_volume: kline.taker_buy_base_asset_volume,
+                taker_buy_quote_asset_volume: kline.taker_buy_quote_asset_volume,
+                ignore: PriceType::default(),
+            },
+        }
+    }
+}
+
+impl KlineEvent {
+    ///
+    /// The kline event time as a date and time.
+    ///
+    pub fn event_datetime(&self) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(self.event_time)
+            .single()
+            .expect("Binance timestamps are within the date and time range")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http_api_v3::data::interval::Interval;
+    use crate::http_api_v3::data::serde::PriceType;
+
+    use super::KlineEvent;
+
+    fn price(value: &str) -> PriceType {
+        value.parse().expect("Valid price")
+    }
+
+    #[test]
+    fn kline_event_is_mapped_to_kline() {
+        let data = r#"{
+            "e": "kline",
+            "E": 1672515782136,
+            "s": "BNBBTC",
+            "k": {
+                "t": 1672515780000,
+                "T": 1672515839999,
+                "s": "BNBBTC",
+                "i": "1m",
+                "f": 100,
+                "L": 200,
+                "o": "0.0010",
+                "c": "0.0020",
+                "h": "0.0025",
+                "l": "0.0015",
+                "v": "1000",
+                "n": 100,
+                "x": true,
+                "q": "1.0000",
+                "V": "500",
+                "Q": "0.500",
+                "B": "123456"
+            }
+        }"#;
+
+        let event: KlineEvent = serde_json::from_str(data).expect("Valid event");
+        assert_eq!(event.event_type, "kline");
+        assert_eq!(event.event_time, 1672515782136);
+        assert_eq!(event.symbol, "BNBBTC");
+        assert_eq!(event.interval, Interval::Minute1);
+        assert!(event.is_closed);
+
+        let kline = event.kline;
+        assert_eq!(kline.open_time, 1672515780000);
+        assert_eq!(kline.close_time, 1672515839999);
+        assert_eq!(kline.open, price("0.0010"));
+        assert_eq!(kline.high, price("0.0025"));
+        assert_eq!(kline.low, price("0.0015"));
+        assert_eq!(kline.close, price("0.0020"));
+        assert_eq!(kline.volume, price("1000"));
+        assert_eq!(kline.quote_asset_volume, price("1.0000"));
+        assert_eq!(kline.number_of_trades, 100);
+        assert_eq!(kline.taker_buy_base_asset_volume, price("500"));
+        assert_eq!(kline.taker_buy_quote_asset_volume, price("0.500"));
+        assert_eq!(kline.ignore, PriceType::default());
+    }
+}
@@ -2,10 +2,16 @@
 //! The merged data, received via WebSocket.
 //!
 
+pub mod account_position;
+pub mod balance_update;
 pub mod depth;
+pub mod execution_report;
+pub mod kline;
 pub mod trade;
+pub mod user_data;
 
 use self::depth::Depth;
+use self::kline::KlineEvent;
 use self::trade::Trade;
 
 ///
@@ -17,4 +23,6 @@ pub enum Event {
     Trade(Trade),
     /// The depth event from the `depth` stream.
     Depth(Depth),
+    /// The kline event from the `kline_<interval>` stream.
+    Kline(KlineEvent),
 }
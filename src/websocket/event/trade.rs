@@ -2,9 +2,12 @@
 //! The trade event data, received via WebSocket.
 //!
 
-use rust_decimal::Decimal;
+use chrono::prelude::*;
 use serde::Deserialize;
 
+use crate::http_api_v3::data::serde::deserialize_price;
+use crate::http_api_v3::data::serde::PriceType;
+
 ///
 /// The trade event data, received via WebSocket.
 ///
@@ -23,11 +26,11 @@ pub struct Trade {
     #[serde(rename = "t")]
     pub trade_id: i64,
     /// The trade event price.
-    #[serde(rename = "p")]
-    pub price: Decimal,
+    #[serde(rename = "p", deserialize_with = "deserialize_price")]
+    pub price: PriceType,
     /// The trade event quantity.
-    #[serde(rename = "q")]
-    pub quantity: Decimal,
+    #[serde(rename = "q", deserialize_with = "deserialize_price")]
+    pub quantity: PriceType,
     /// The order ID if the buying side.
     #[serde(rename = "b")]
     pub buyer_order_id: i64,
@@ -44,3 +47,23 @@ pub struct Trade {
     #[serde(rename = "M")]
     pub ignore: bool,
 }
+
+impl Trade {
+    ///
+    /// The trade event time as a date and time.
+    ///
+    pub fn event_datetime(&self) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(self.event_time)
+            .single()
+            .expect("Binance timestamps are within the date and time range")
+    }
+
+    ///
+    /// The trade time as a date and time.
+    ///
+    pub fn trade_datetime(&self) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(self.trade_time)
+            .single()
+            .expect("Binance timestamps are within the date and time range")
+    }
+}
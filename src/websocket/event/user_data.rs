@@ -0,0 +1,154 @@
+//!
+//! The user data event, received via the user data stream.
+//!
+
+use serde::Deserialize;
+
+use super::account_position::AccountPosition;
+use super::balance_update::BalanceUpdate;
+use super::execution_report::ExecutionReport;
+
+///
+/// The user data event, received via the user data stream.
+///
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "e")]
+pub enum UserDataEvent {
+    /// The account balances have changed.
+    #[serde(rename = "outboundAccountPosition")]
+    AccountPosition(AccountPosition),
+    /// The balance has changed due to a deposit, withdrawal, or transfer.
+    #[serde(rename = "balanceUpdate")]
+    BalanceUpdate(BalanceUpdate),
+    /// The order has been updated.
+    #[serde(rename = "executionReport")]
+    ExecutionReport(Box<ExecutionReport>),
+    /// Fallback for all other events, e.g. `listenKeyExpired`.
+    #[serde(other)]
+    Other,
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use crate::http_api_v3::data::order_side::OrderSide;
+    use crate::http_api_v3::data::order_status::OrderStatus;
+    use crate::http_api_v3::data::order_time_in_force::OrderTimeInForce;
+    use crate::http_api_v3::data::order_type::OrderType;
+    use crate::websocket::event::execution_report::ExecutionType;
+
+    use super::UserDataEvent;
+
+    #[test]
+    fn execution_report_is_parsed() {
+        let data = r#"{
+            "e": "executionReport",
+            "E": 1499405658658,
+            "s": "ETHBTC",
+            "c": "mUvoqJxFIILMdfAW5iGSOW",
+            "S": "BUY",
+            "o": "LIMIT",
+            "f": "GTC",
+            "q": "1.00000000",
+            "p": "0.10264410",
+            "P": "0.00000000",
+            "F": "0.00000000",
+            "g": -1,
+            "C": "",
+            "x": "NEW",
+            "X": "NEW",
+            "r": "NONE",
+            "i": 4293153,
+            "l": "0.00000000",
+            "z": "0.00000000",
+            "L": "0.00000000",
+            "n": "0",
+            "N": null,
+            "T": 1499405658657,
+            "t": -1,
+            "I": 8641984,
+            "w": true,
+            "m": false,
+            "M": false,
+            "O": 1499405658657,
+            "Z": "0.00000000",
+            "Y": "0.00000000",
+            "Q": "0.00000000"
+        }"#;
+
+        let report = match serde_json::from_str(data).expect("Valid event") {
+            UserDataEvent::ExecutionReport(report) => report,
+            event => panic!("expected an execution report, got {:?}", event),
+        };
+        assert_eq!(report.symbol, "ETHBTC");
+        assert_eq!(report.client_order_id, "mUvoqJxFIILMdfAW5iGSOW");
+        assert_eq!(report.side, OrderSide::Buy);
+        assert_eq!(report.r#type, OrderType::Limit);
+        assert_eq!(report.time_in_force, OrderTimeInForce::GoodTilCanceled);
+        assert_eq!(report.quantity, Decimal::new(1, 0));
+        assert_eq!(report.price, Decimal::new(10264410, 8));
+        assert_eq!(report.execution_type, ExecutionType::New);
+        assert_eq!(report.status, OrderStatus::New);
+        assert_eq!(report.order_id, 4293153);
+        assert_eq!(report.commission_asset, None);
+        assert_eq!(report.trade_id, -1);
+        assert!(report.is_working);
+        assert!(!report.is_trade());
+    }
+
+    #[test]
+    fn account_position_is_parsed() {
+        let data = r#"{
+            "e": "outboundAccountPosition",
+            "E": 1564034571105,
+            "u": 1564034571073,
+            "B": [
+                {
+                    "a": "ETH",
+                    "f": "10000.000000",
+                    "l": "0.000000"
+                }
+            ]
+        }"#;
+
+        let position = match serde_json::from_str(data).expect("Valid event") {
+            UserDataEvent::AccountPosition(position) => position,
+            event => panic!("expected an account position, got {:?}", event),
+        };
+        assert_eq!(position.event_time, 1564034571105);
+        assert_eq!(position.last_update_time, 1564034571073);
+        assert_eq!(position.balances.len(), 1);
+        assert_eq!(position.balances[0].asset, "ETH");
+        assert_eq!(position.balances[0].free, Decimal::new(10000, 0));
+        assert_eq!(position.balances[0].locked, Decimal::new(0, 0));
+    }
+
+    #[test]
+    fn balance_update_is_parsed() {
+        let data = r#"{
+            "e": "balanceUpdate",
+            "E": 1573200697110,
+            "a": "BTC",
+            "d": "100.00000000",
+            "T": 1573200697068
+        }"#;
+
+        let update = match serde_json::from_str(data).expect("Valid event") {
+            UserDataEvent::BalanceUpdate(update) => update,
+            event => panic!("expected a balance update, got {:?}", event),
+        };
+        assert_eq!(update.asset, "BTC");
+        assert_eq!(update.delta, Decimal::new(100, 0));
+        assert_eq!(update.clear_time, 1573200697068);
+    }
+
+    #[test]
+    fn unknown_event_is_skipped() {
+        let data = r#"{"e":"listenKeyExpired","E":1576653824250}"#;
+        match serde_json::from_str(data).expect("Valid event") {
+            UserDataEvent::Other => {}
+            event => panic!("expected the fallback, got {:?}", event),
+        }
+    }
+}
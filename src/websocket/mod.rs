@@ -2,7 +2,11 @@
 //! The Binance WebSocket adapter.
 //!
 
+pub mod candle_builder;
+pub mod combined;
 pub mod event;
+pub mod order_book;
+pub mod user_data;
 
 use std::sync::mpsc;
 use std::thread;
@@ -0,0 +1,360 @@
+//!
+//! The local order book, which is synchronized via the depth snapshot and the WebSocket diffs.
+//!
+
+use std::cmp::Ordering;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+use websocket::client::ClientBuilder;
+use websocket::OwnedMessage;
+
+use crate::error::Error;
+use crate::http_api_v3::data::depth::get::request::Query as DepthGetQuery;
+use crate::http_api_v3::data::depth::get::response::Response as DepthGetResponse;
+use crate::http_api_v3::data::depth_element::DepthElement;
+use crate::http_api_v3::data::serde::PriceType;
+use crate::http_api_v3::Client as HttpApiV3Client;
+
+use super::event::depth::Depth;
+
+///
+/// The local order book, which applies the WebSocket depth diffs to the REST depth snapshot
+/// according to the Binance synchronization algorithm.
+///
+#[derive(Debug, Clone)]
+pub struct LocalOrderBook {
+    /// The last applied update ID.
+    last_update_id: i64,
+    /// The bids sorted by price in descending order.
+    bids: Vec<DepthElement>,
+    /// The asks sorted by price in ascending order.
+    asks: Vec<DepthElement>,
+}
+
+impl LocalOrderBook {
+    /// The depth stream base URL.
+    const BASE_URL: &'static str = "wss://stream.binance.com:9443/ws";
+    /// The depth snapshot limit, which is the largest one with the moderate weight.
+    const SNAPSHOT_LIMIT: i64 = 1000;
+    /// The initial delay before requesting the snapshot again after a failed or stale one.
+    const SNAPSHOT_BACKOFF_INITIAL: Duration = Duration::from_secs(1);
+    /// The maximal delay before requesting the snapshot again.
+    const SNAPSHOT_BACKOFF_MAX: Duration = Duration::from_secs(30);
+
+    ///
+    /// Creates the order book from the REST depth snapshot.
+    ///
+    pub fn from_snapshot(snapshot: DepthGetResponse) -> Self {
+        let mut book = Self {
+            last_update_id: snapshot.last_update_id,
+            bids: Vec::with_capacity(snapshot.bids.len()),
+            asks: Vec::with_capacity(snapshot.asks.len()),
+        };
+        for bid in snapshot.bids.into_iter() {
+            Self::update(&mut book.bids, bid, true);
+        }
+        for ask in snapshot.asks.into_iter() {
+            Self::update(&mut book.asks, ask, false);
+        }
+        book
+    }
+
+    ///
+    /// Applies the depth diff and returns `true`, or returns `false` if the diff is older than
+    /// the book and is skipped.
+    ///
+    /// Fails with `OrderBookGap` if some diffs between the book and this one are missing, in
+    /// which case the book must be created from a new snapshot.
+    ///
+    pub fn apply(&mut self, depth: &Depth) -> Result<bool, Error> {
+        if depth.last_update_id <= self.last_update_id {
+            return Ok(false);
+        }
+        if depth.first_update_id > self.last_update_id + 1 {
+            return Err(Error::OrderBookGap(
+                self.last_update_id + 1,
+                depth.first_update_id,
+            ));
+        }
+
+        for bid in depth.bids.iter() {
+            Self::update(&mut self.bids, bid.to_owned(), true);
+        }
+        for ask in depth.asks.iter() {
+            Self::update(&mut self.asks, ask.to_owned(), false);
+        }
+        self.last_update_id = depth.last_update_id;
+        Ok(true)
+    }
+
+    ///
+    /// The top `depth` levels on each side, in the same format as the REST depth snapshot.
+    ///
+    pub fn top(&self, depth: usize) -> DepthGetResponse {
+        DepthGetResponse {
+            last_update_id: self.last_update_id,
+            bids: self.bids.iter().take(depth).cloned().collect(),
+            asks: self.asks.iter().take(depth).cloned().collect(),
+        }
+    }
+
+    ///
+    /// The last applied update ID.
+    ///
+    pub fn last_update_id(&self) -> i64 {
+        self.last_update_id
+    }
+
+    ///
+    /// Connects to the `symbol` depth stream, synchronizes the book with the `client` depth
+    /// snapshot, and sends the top `depth` levels after each applied diff.
+    ///
+    /// The diffs are buffered by the connection while the snapshot is being fetched. The diffs
+    /// preceding the snapshot are dropped, and the book is synchronized again from a new snapshot
+    /// if a diff is missing. The receiver is disconnected when the connection is closed.
+    ///
+    /// After a failed or stale snapshot, the next one is only requested after a delay, which
+    /// starts at 1 second and doubles up to 30 seconds, since each snapshot has the weight of 50.
+    ///
+    pub fn maintain(
+        client: HttpApiV3Client,
+        symbol: &str,
+        depth: usize,
+    ) -> Result<mpsc::Receiver<DepthGetResponse>, Error> {
+        let address = format!(
+            "{}/{}@depth@100ms",
+            Self::BASE_URL,
+            symbol.to_ascii_lowercase()
+        );
+        let mut connection = ClientBuilder::new(&address)
+            .expect("WebSocket address is valid")
+            .connect_secure(None)
+            .map_err(Error::WebSocket)?;
+
+        let symbol = symbol.to_ascii_uppercase();
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let mut book: Option<Self> = None;
+            let mut backoff = Self::SNAPSHOT_BACKOFF_INITIAL;
+            let mut retry_at = Instant::now();
+            loop {
+                let message = match connection.recv_message() {
+                    Ok(OwnedMessage::Text(message)) => message,
+                    Ok(OwnedMessage::Ping(payload)) => {
+                        log::debug!("Received ping");
+                        match connection.send_message(&OwnedMessage::Pong(payload)) {
+                            Ok(()) => log::debug!("Sent pong"),
+                            Err(error) => log::warn!("Pong sending error: {}", error),
+                        }
+                        continue;
+                    }
+                    Ok(OwnedMessage::Close(_)) => {
+                        log::debug!("Websocket closed");
+                        return;
+                    }
+                    Ok(_) => continue,
+                    Err(error) => {
+                        log::error!("Websocket error: {}", error);
+                        return;
+                    }
+                };
+
+                let diff = match serde_json::from_str::<Depth>(message.as_str()) {
+                    Ok(diff) => diff,
+                    Err(error) => {
+                        log::warn!("Parsing error: {} ({:?})", error, message);
+                        continue;
+                    }
+                };
+
+                if book.is_none() {
+                    if Instant::now() < retry_at {
+                        continue;
+                    }
+                    let snapshot = match client.depth(DepthGetQuery::new(
+                        symbol.as_str(),
+                        Some(Self::SNAPSHOT_LIMIT),
+                    )) {
+                        Ok(snapshot) if snapshot.last_update_id + 1 < diff.first_update_id => {
+                            log::debug!("Depth snapshot is older than the diffs");
+                            None
+                        }
+                        Ok(snapshot) => Some(snapshot),
+                        Err(error) => {
+                            log::warn!("Depth snapshot error: {}", error);
+                            None
+                        }
+                    };
+                    match snapshot {
+                        Some(snapshot) => book = Some(Self::from_snapshot(snapshot)),
+                        None => {
+                            log::debug!("Requesting the depth snapshot again in {:?}", backoff);
+                            retry_at = Instant::now() + backoff;
+                            backoff = (backoff * 2).min(Self::SNAPSHOT_BACKOFF_MAX);
+                            continue;
+                        }
+                    }
+                }
+
+                let synced = book.as_mut().expect("Always exists");
+                match synced.apply(&diff) {
+                    Ok(true) => {
+                        backoff = Self::SNAPSHOT_BACKOFF_INITIAL;
+                        if tx.send(synced.top(depth)).is_err() {
+                            return;
+                        }
+                    }
+                    Ok(false) => {}
+                    Err(error) => {
+                        log::warn!("{}, synchronizing again", error);
+                        book = None;
+                    }
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+
+    ///
+    /// Sets the level quantity, removing the level if the quantity is zero.
+    ///
+    /// The bids are kept in descending order, and the asks are in ascending one.
+    ///
+    fn update(levels: &mut Vec<DepthElement>, level: DepthElement, is_bid: bool) {
+        let position = levels.binary_search_by(|probe| {
+            let ordering = probe
+                .price
+                .partial_cmp(&level.price)
+                .unwrap_or(Ordering::Equal);
+            if is_bid {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+
+        let is_removed = level.quantity == PriceType::default();
+        match position {
+            Ok(index) if is_removed => {
+                levels.remove(index);
+            }
+            Ok(index) => levels[index] = level,
+            Err(_) if is_removed => {}
+            Err(index) => levels.insert(index, level),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::http_api_v3::data::depth::get::response::Response as DepthGetResponse;
+    use crate::http_api_v3::data::depth_element::DepthElement;
+    use crate::http_api_v3::data::serde::parse_price;
+    use crate::websocket::event::depth::Depth;
+
+    use super::LocalOrderBook;
+
+    fn level(price: &str, quantity: &str) -> DepthElement {
+        DepthElement {
+            price: parse_price(price).expect("Valid price"),
+            quantity: parse_price(quantity).expect("Valid quantity"),
+        }
+    }
+
+    fn book() -> LocalOrderBook {
+        LocalOrderBook::from_snapshot(DepthGetResponse {
+            last_update_id: 100,
+            bids: vec![level("9.0", "1"), level("10.0", "1")],
+            asks: vec![level("12.0", "1"), level("11.0", "1")],
+        })
+    }
+
+    fn diff(
+        first_update_id: i64,
+        last_update_id: i64,
+        bids: Vec<DepthElement>,
+        asks: Vec<DepthElement>,
+    ) -> Depth {
+        Depth {
+            event_type: "depthUpdate".to_owned(),
+            event_time: 0,
+            symbol: "BTCUSDT".to_owned(),
+            first_update_id,
+            last_update_id,
+            bids,
+            asks,
+        }
+    }
+
+    fn assert_levels(levels: &[DepthElement], expected: &[(&str, &str)]) {
+        let expected: Vec<DepthElement> = expected
+            .iter()
+            .map(|(price, quantity)| level(price, quantity))
+            .collect();
+        assert_eq!(levels.len(), expected.len());
+        for (level, expected) in levels.iter().zip(expected.iter()) {
+            assert_eq!(level.price, expected.price);
+            assert_eq!(level.quantity, expected.quantity);
+        }
+    }
+
+    #[test]
+    fn stale_diff_is_skipped() {
+        let mut book = book();
+        let applied = book
+            .apply(&diff(90, 100, vec![level("10.0", "5")], vec![]))
+            .expect("No gap");
+        assert!(!applied);
+        assert_eq!(book.last_update_id(), 100);
+        assert_levels(&book.top(10).bids, &[("10.0", "1"), ("9.0", "1")]);
+    }
+
+    #[test]
+    fn gap_is_reported() {
+        let mut book = book();
+        match book.apply(&diff(102, 110, vec![], vec![])) {
+            Err(Error::OrderBookGap(101, 102)) => {}
+            result => panic!("expected OrderBookGap, got {:?}", result),
+        }
+        assert_eq!(book.last_update_id(), 100);
+    }
+
+    #[test]
+    fn zero_quantity_removes_level() {
+        let mut book = book();
+        let applied = book
+            .apply(&diff(
+                95,
+                105,
+                vec![level("10.0", "0")],
+                vec![level("11.0", "0.00000000")],
+            ))
+            .expect("No gap");
+        assert!(applied);
+        assert_eq!(book.last_update_id(), 105);
+        assert_levels(&book.top(10).bids, &[("9.0", "1")]);
+        assert_levels(&book.top(10).asks, &[("12.0", "1")]);
+    }
+
+    #[test]
+    fn levels_are_ordered() {
+        let mut book = book();
+        book.apply(&diff(
+            101,
+            101,
+            vec![level("9.5", "2"), level("10.0", "3")],
+            vec![level("11.5", "2"), level("13.0", "1")],
+        ))
+        .expect("No gap");
+        let top = book.top(3);
+        assert_eq!(top.last_update_id, 101);
+        assert_levels(&top.bids, &[("10.0", "3"), ("9.5", "2"), ("9.0", "1")]);
+        assert_levels(&top.asks, &[("11.0", "1"), ("11.5", "2"), ("12.0", "1")]);
+    }
+}
@@ -0,0 +1,165 @@
+//!
+//! The Binance WebSocket user data stream.
+//!
+
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use websocket::client::ClientBuilder;
+use websocket::OwnedMessage;
+
+use crate::error::Error;
+use crate::http_api_v3::Client as HttpApiV3Client;
+
+use super::event::user_data::UserDataEvent;
+
+///
+/// The Binance WebSocket user data stream, which sends the account and order updates.
+///
+#[derive(Debug)]
+pub struct UserDataStream {
+    /// The user data event receiver.
+    events: mpsc::Receiver<UserDataEvent>,
+    /// The listen key keepalive, if the stream manages its listen key.
+    keepalive: Option<Keepalive>,
+}
+
+///
+/// The listen key keepalive, which refreshes the key in a background thread until dropped.
+///
+#[derive(Debug)]
+pub struct Keepalive {
+    /// The stop signal sender, which stops the thread when dropped.
+    _stop: mpsc::Sender<()>,
+    /// The keepalive error receiver.
+    errors: mpsc::Receiver<Error>,
+}
+
+impl UserDataStream {
+    /// The user data stream base URL.
+    const BASE_URL: &'static str = "wss://stream.binance.com:9443/ws";
+    /// The listen key keepalive interval, which is a half of the key validity time.
+    pub const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+    ///
+    /// Connects to the user data stream of `listen_key`.
+    ///
+    pub fn connect(listen_key: &str) -> Result<Self, Error> {
+        let address = format!("{}/{}", Self::BASE_URL, listen_key);
+        let mut client = ClientBuilder::new(&address)
+            .expect("WebSocket address is valid")
+            .connect_secure(None)
+            .map_err(Error::WebSocket)?;
+
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || loop {
+            let message = match client.recv_message() {
+                Ok(OwnedMessage::Text(message)) => message,
+                Ok(OwnedMessage::Ping(payload)) => {
+                    log::debug!("Received ping");
+                    match client.send_message(&OwnedMessage::Pong(payload)) {
+                        Ok(()) => log::debug!("Sent pong"),
+                        Err(error) => log::warn!("Pong sending error: {}", error),
+                    }
+                    continue;
+                }
+                Ok(OwnedMessage::Close(_)) => {
+                    log::debug!("Websocket closed");
+                    return;
+                }
+                Ok(_) => continue,
+                Err(error) => {
+                    log::error!("Websocket error: {}", error);
+                    return;
+                }
+            };
+
+            match serde_json::from_str::<UserDataEvent>(message.as_str()) {
+                Ok(event) => {
+                    if tx.send(event).is_err() {
+                        return;
+                    }
+                }
+                Err(error) => log::warn!("Parsing error: {} ({:?})", error, message),
+            }
+        });
+
+        Ok(Self {
+            events: rx,
+            keepalive: None,
+        })
+    }
+
+    ///
+    /// Starts a new user data stream with the authorized `client`, connects to it, and keeps its
+    /// listen key alive every `KEEPALIVE_INTERVAL` until the stream is dropped.
+    ///
+    /// The listen key is left to expire after the stream is dropped.
+    ///
+    pub fn connect_with_keepalive(client: &HttpApiV3Client) -> Result<Self, Error> {
+        let listen_key = client.user_data_stream_post()?.listen_key;
+        let mut stream = Self::connect(listen_key.as_str())?;
+        stream.keepalive = Some(Keepalive::spawn(
+            client.clone(),
+            listen_key,
+            Self::KEEPALIVE_INTERVAL,
+        ));
+        Ok(stream)
+    }
+
+    ///
+    /// The user data event receiver, which is disconnected when the connection is closed.
+    ///
+    pub fn events(&self) -> &mpsc::Receiver<UserDataEvent> {
+        &self.events
+    }
+
+    ///
+    /// The listen key keepalive error receiver, if the stream manages its listen key.
+    ///
+    /// A failed keepalive is retried on the next interval, but the stream stops after the key
+    /// expires.
+    ///
+    pub fn keepalive_errors(&self) -> Option<&mpsc::Receiver<Error>> {
+        self.keepalive.as_ref().map(|keepalive| keepalive.errors())
+    }
+}
+
+impl Keepalive {
+    ///
+    /// Spawns the thread, which keeps `listen_key` alive with `client` every `interval`.
+    ///
+    pub fn spawn(client: HttpApiV3Client, listen_key: String, interval: Duration) -> Self {
+        let (stop_tx, stop_rx) = mpsc::channel::<()>();
+        let (errors_tx, errors_rx) = mpsc::channel();
+
+        thread::spawn(move || loop {
+            match stop_rx.recv_timeout(interval) {
+                Err(mpsc::RecvTimeoutError::Timeout) => {}
+                Ok(()) | Err(mpsc::RecvTimeoutError::Disconnected) => return,
+            }
+
+            match client.user_data_stream_put(listen_key.as_str()) {
+                Ok(()) => log::debug!("Listen key kept alive"),
+                Err(error) => {
+                    log::warn!("Listen key keepalive error: {}", error);
+                    let _ = errors_tx.send(error);
+                }
+            }
+        });
+
+        Self {
+            _stop: stop_tx,
+            errors: errors_rx,
+        }
+    }
+
+    ///
+    /// The keepalive error receiver.
+    ///
+    pub fn errors(&self) -> &mpsc::Receiver<Error> {
+        &self.errors
+    }
+}